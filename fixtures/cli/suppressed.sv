@@ -0,0 +1,10 @@
+// sv-mint: disable-next-line=module_names_lower_snake
+module MyModule;
+endmodule
+
+module OtherModule; // sv-mint: disable-line=module_names_lower_snake
+endmodule
+
+// sv-mint: disable-line=module_names_lower_snake
+module Loud_Module;
+endmodule
@@ -1,5 +1,18 @@
 from lib.cst_inline import Cst, byte_span_to_loc
 
+RULES = [
+    {
+        "id": "always_comb_uses_blocking",
+        "description": "always_comb blocks use blocking '=' assignments, not '<='",
+        "rationale": (
+            "Nonblocking assignments in combinational logic delay updates to the next "
+            "delta cycle and can make simulation disagree with synthesis."
+        ),
+        "default_severity": "warning",
+        "tags": ["style", "synthesis"],
+    },
+]
+
 def check(req):
     if req.get("stage") != "cst":
         return []
@@ -1,5 +1,18 @@
 from lib.cst_inline import Cst, byte_span_to_loc
 
+RULES = [
+    {
+        "id": "always_ff_uses_nonblocking",
+        "description": "always_ff blocks use nonblocking '<=' assignments, not '='",
+        "rationale": (
+            "Blocking assignments in sequential logic create order-dependent races "
+            "between flops that simulate differently from the synthesized netlist."
+        ),
+        "default_severity": "warning",
+        "tags": ["style", "synthesis"],
+    },
+]
+
 def check(req):
     if req.get("stage") != "cst":
         return []
@@ -10,6 +23,7 @@ def check(req):
     tokens = ir.get("tokens") or []
     tok_kinds = ir.get("tok_kind_map") or {}
     op_eq = tok_kinds.get("op_eq")
+    data = (ir.get("source_text") or "").encode("utf-8")
     out = []
     if tokens and op_eq is not None:
         for node in cst.of_kind("AlwaysConstruct"):
@@ -31,12 +45,29 @@ def check(req):
                     break
                 if tok.get("kind") == op_eq:
                     loc = byte_span_to_loc(ts, te, line_starts)
-                    out.append(
-                        {
-                            "rule_id": "always_ff_uses_nonblocking",
-                            "severity": "warning",
-                            "message": "blocking '=' inside always_ff",
-                            "location": loc,
-                        }
-                    )
+                    violation = {
+                        "rule_id": "always_ff_uses_nonblocking",
+                        "severity": "warning",
+                        "message": "blocking '=' inside always_ff",
+                        "location": loc,
+                    }
+                    if _outside_parens(data, start, ts):
+                        violation["fixes"] = [
+                            {
+                                "description": "use nonblocking '<='",
+                                "edits": [{"start": ts, "end": te, "replacement": "<="}],
+                            }
+                        ]
+                    out.append(violation)
     return out
+
+def _outside_parens(data, start, pos):
+    if not data:
+        return False
+    depth = 0
+    for b in data[start:pos]:
+        if b == 0x28:
+            depth += 1
+        elif b == 0x29:
+            depth -= 1
+    return depth == 0
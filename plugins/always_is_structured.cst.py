@@ -1,5 +1,18 @@
 from lib.cst_inline import Cst, byte_span_to_loc
 
+RULES = [
+    {
+        "id": "always_is_structured",
+        "description": "use always_ff/always_comb/always_latch instead of a bare always",
+        "rationale": (
+            "always_ff/always_comb/always_latch state the designer's intent so tools can "
+            "check that the block really infers flops, logic or latches."
+        ),
+        "default_severity": "warning",
+        "tags": ["style"],
+    },
+]
+
 def check(req):
     if req.get("stage") != "cst":
         return []
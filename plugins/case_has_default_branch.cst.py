@@ -1,5 +1,18 @@
 from lib.cst_inline import Cst, byte_span_to_loc
 
+RULES = [
+    {
+        "id": "case_has_default_branch",
+        "description": "case statements include a default item",
+        "rationale": (
+            "A missing default item leaves unlisted values unhandled, which infers "
+            "latches in combinational logic and hides X propagation."
+        ),
+        "default_severity": "warning",
+        "tags": ["style"],
+    },
+]
+
 def check(req):
     if req.get("stage") != "cst":
         return []
@@ -1,5 +1,18 @@
 from lib.cst_inline import byte_span_to_loc
 
+RULES = [
+    {
+        "id": "default_nettype_begins_with_none",
+        "description": "files start with `default_nettype none`",
+        "rationale": (
+            "With implicit nets enabled a typo in a port connection silently creates a "
+            "new 1-bit wire instead of failing to compile."
+        ),
+        "default_severity": "warning",
+        "tags": ["preprocessor"],
+    },
+]
+
 def check(req):
     if req.get("stage") != "cst":
         return []
@@ -3,6 +3,19 @@ from pathlib import Path
 
 from lib.utf8 import line_starts, span_to_loc
 
+RULES = [
+    {
+        "id": "default_nettype_ends_with_wire",
+        "description": "files that set `default_nettype none` restore `wire` at the end",
+        "rationale": (
+            "`default_nettype leaks into files compiled later; restoring wire keeps "
+            "third-party code that relies on implicit nets compiling."
+        ),
+        "default_severity": "warning",
+        "tags": ["preprocessor"],
+    },
+]
+
 def check(req):
     if req.get("stage") != "cst":
         return []
@@ -1,5 +1,18 @@
 from lib.cst_inline import Cst, byte_span_to_loc
 
+RULES = [
+    {
+        "id": "disable_targets_fork_only",
+        "description": "disable targets fork rather than a named block",
+        "rationale": (
+            "Disabling a named block is poorly supported across simulators and kills "
+            "every process in that scope; disable fork is explicit and portable."
+        ),
+        "default_severity": "warning",
+        "tags": ["portability"],
+    },
+]
+
 def check(req):
     if req.get("stage") != "cst":
         return []
@@ -2,6 +2,16 @@ import re
 
 from lib.utf8 import span_to_loc
 
+RULES = [
+    {
+        "id": "enum_type_names_lower_snake_e",
+        "description": "enum type names use lower_snake_case and end with _e",
+        "rationale": "A consistent _e suffix makes enum types recognisable at their point of use.",
+        "default_severity": "warning",
+        "tags": ["naming"],
+    },
+]
+
 TYPEDEF_ENUM_RE = re.compile(
     r"typedef\s+enum(?P<head>[\s\S]*?)\{(?P<body>[\s\S]*?)\}\s*(?P<name>[A-Za-z_]\w*)\s*;",
     re.DOTALL,
@@ -2,6 +2,19 @@ import re
 
 from lib.utf8 import span_to_loc
 
+RULES = [
+    {
+        "id": "enum_values_uppercase",
+        "description": "enum values use UpperCamelCase or ALL_CAPS",
+        "rationale": (
+            "Capitalised enum values read as constants and do not collide with signal "
+            "names."
+        ),
+        "default_severity": "warning",
+        "tags": ["naming"],
+    },
+]
+
 TYPEDEF_ENUM_RE = re.compile(
     r"typedef\s+enum(?P<head>[\s\S]*?)\{(?P<body>[\s\S]*?)\}\s*(?P<name>[A-Za-z_]\w*)\s*;",
     re.DOTALL,
@@ -1,5 +1,18 @@
 from lib.cst_inline import Cst, byte_span_to_loc
 
+RULES = [
+    {
+        "id": "functions_explicit_return_type",
+        "description": "functions declare an explicit return type",
+        "rationale": (
+            "Functions without a return type return a 1-bit logic, truncating the result "
+            "without any warning."
+        ),
+        "default_severity": "warning",
+        "tags": ["style"],
+    },
+]
+
 def check(req):
     if req.get("stage") != "cst":
         return []
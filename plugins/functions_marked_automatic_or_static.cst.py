@@ -1,5 +1,18 @@
 from lib.cst_inline import Cst, byte_span_to_loc
 
+RULES = [
+    {
+        "id": "functions_marked_automatic_or_static",
+        "description": "functions in modules/packages/interfaces declare automatic or static",
+        "rationale": (
+            "Functions in modules are static by default, so recursive or concurrent calls"
+            " share storage unless the lifetime is stated."
+        ),
+        "default_severity": "warning",
+        "tags": ["style"],
+    },
+]
+
 def check(req):
     if req.get("stage") != "cst":
         return []
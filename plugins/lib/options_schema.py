@@ -0,0 +1,45 @@
+_TYPES = {
+    "object": dict,
+    "array": list,
+    "string": str,
+    "boolean": bool,
+    "null": type(None),
+}
+
+def _type_ok(value, expected):
+    if expected == "integer":
+        return isinstance(value, int) and not isinstance(value, bool)
+    if expected == "number":
+        return isinstance(value, (int, float)) and not isinstance(value, bool)
+    py_type = _TYPES.get(expected)
+    return py_type is not None and isinstance(value, py_type)
+
+def validate(value, schema, path="options"):
+    errors = []
+    if not isinstance(schema, dict):
+        return errors
+    expected = schema.get("type")
+    if expected is not None:
+        choices = expected if isinstance(expected, list) else [expected]
+        if not any(_type_ok(value, t) for t in choices):
+            errors.append(f"{path}: expected {' or '.join(choices)}")
+            return errors
+    if "enum" in schema and value not in schema["enum"]:
+        errors.append(f"{path}: must be one of {schema['enum']}")
+    if isinstance(value, dict):
+        props = schema.get("properties") or {}
+        for key in schema.get("required") or []:
+            if key not in value:
+                errors.append(f"{path}.{key}: required")
+        extra = schema.get("additionalProperties", True)
+        for key, item in value.items():
+            if key in props:
+                errors.extend(validate(item, props[key], f"{path}.{key}"))
+            elif extra is False:
+                errors.append(f"{path}.{key}: unknown option")
+            elif isinstance(extra, dict):
+                errors.extend(validate(item, extra, f"{path}.{key}"))
+    if isinstance(value, list) and "items" in schema:
+        for idx, item in enumerate(value):
+            errors.extend(validate(item, schema["items"], f"{path}[{idx}]"))
+    return errors
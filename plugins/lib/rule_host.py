@@ -1,8 +1,16 @@
 import importlib.util
 import json
 import sys
+import time
+import traceback
 from pathlib import Path
 
+from options_schema import validate as validate_options
+
+PROTOCOL_VERSION = 1
+CAPABILITIES = ["fixes", "metadata"]
+SCHEMAS = {"cst_ir": [2], "ast": [1]}
+
 def prepend_paths():
     base = Path(__file__).resolve().parent.parent
     lib = base / "lib"
@@ -18,12 +26,68 @@ def load_module(path, index):
     spec.loader.exec_module(module)
     return module
 
+def check_options(module, stage_rules, options):
+    schema = getattr(module, "OPTIONS_SCHEMA", None)
+    if schema is None:
+        return None
+    for ids in stage_rules.values():
+        for rule_id in ids or []:
+            errors = validate_options(options.get(rule_id) or {}, schema, f"rule {rule_id} options")
+            if errors:
+                return "; ".join(errors)
+    return None
+
+def check_dispatch_options(module, index, stage_name, ids, options, cache):
+    if getattr(module, "OPTIONS_SCHEMA", None) is None:
+        return None
+    selected = {rule_id: options.get(rule_id) or {} for rule_id in ids}
+    key = (index, json.dumps(selected, sort_keys=True))
+    if key not in cache:
+        cache[key] = check_options(module, {stage_name: ids}, selected)
+    return cache[key]
+
+def crash_violation(meta, stage_name, exc):
+    frames = traceback.extract_tb(exc.__traceback__)[-3:]
+    where = "; ".join(f"{Path(f.filename).name}:{f.lineno} in {f.name}" for f in frames)
+    ids = sorted(meta["rules"].get(stage_name) or [])
+    rules = f" (rules: {', '.join(ids)})" if ids else ""
+    message = f"script {meta['path']}{rules} crashed: {type(exc).__name__}: {exc}"
+    if where:
+        message += f" [traceback: {where}]"
+    return {
+        "rule_id": "sys.plugin.rule_crashed",
+        "severity": "error",
+        "message": message,
+        "location": {"line": 1, "col": 1, "end_line": 1, "end_col": 1},
+    }
+
+def describe(modules, script_meta):
+    rules = []
+    for module, meta in zip(modules, script_meta):
+        for entry in getattr(module, "RULES", None) or []:
+            item = {
+                "id": entry.get("id"),
+                "description": entry.get("description"),
+                "rationale": entry.get("rationale"),
+                "default_severity": entry.get("default_severity"),
+                "tags": list(entry.get("tags") or []),
+                "docs_url": entry.get("docs_url"),
+                "script": meta["path"],
+            }
+            if item["id"]:
+                rules.append(item)
+    return rules
+
 def main():
     prepend_paths()
     first = sys.stdin.readline()
     if not first:
         return
     init = json.loads(first)
+    if init.get("protocol_version") != PROTOCOL_VERSION:
+        print(json.dumps({"type": "ready", "protocol_version": PROTOCOL_VERSION}))
+        sys.stdout.flush()
+        return
     scripts = init.get("scripts") or []
     modules = []
     script_meta = []
@@ -32,6 +96,11 @@ def main():
         stages = script.get("stages") or []
         stage_rules = script.get("stage_rules") or {}
         mod = load_module(Path(path), idx)
+        problem = check_options(mod, stage_rules, script.get("options") or {})
+        if problem:
+            print(json.dumps({"type": "error", "detail": problem, "script": str(Path(path))}))
+            sys.stdout.flush()
+            return
         modules.append(mod)
         rules_by_stage = {}
         for stage_name, ids in stage_rules.items():
@@ -43,8 +112,15 @@ def main():
                 "path": str(Path(path)),
             }
         )
-    print(json.dumps({"type": "ready"}))
+    ready = {
+        "type": "ready",
+        "protocol_version": PROTOCOL_VERSION,
+        "capabilities": CAPABILITIES,
+        "schemas": SCHEMAS,
+    }
+    print(json.dumps(ready))
     sys.stdout.flush()
+    validated = {}
     for line in sys.stdin:
         if not line:
             break
@@ -52,12 +128,18 @@ def main():
         kind = req.get("kind")
         if kind == "shutdown":
             break
+        if kind == "describe":
+            print(json.dumps({"type": "rules", "rules": describe(modules, script_meta)}))
+            sys.stdout.flush()
+            continue
         results = []
-        error = None
+        timings = []
         stage_name = req.get("stage")
         rules_cfg = req.get("rules") or {}
         enabled_rules = set(rules_cfg.get("enabled") or [])
-        for module, meta in zip(modules, script_meta):
+        rule_options = rules_cfg.get("options") or {}
+        problem = None
+        for index, (module, meta) in enumerate(zip(modules, script_meta)):
             stages = meta["stages"]
             if stages and stage_name not in stages:
                 continue
@@ -67,22 +149,26 @@ def main():
             handler = getattr(module, "check", None)
             if handler is None:
                 continue
+            if stage_rules is not None:
+                ids = sorted(stage_rules & enabled_rules)
+                problem = check_dispatch_options(module, index, stage_name, ids, rule_options, validated)
+                if problem:
+                    problem = {"type": "error", "detail": problem, "script": meta["path"]}
+                    break
+            started = time.perf_counter()
             try:
                 out = handler(req)
             except Exception as exc:
-                error = {
-                    "type": "error",
-                    "detail": str(exc),
-                    "script": meta.get("path"),
-                }
-                break
+                out = [crash_violation(meta, stage_name, exc)]
+            elapsed_ms = (time.perf_counter() - started) * 1000.0
+            ids = sorted(stage_rules & enabled_rules) if stage_rules is not None else [Path(meta["path"]).stem]
+            timings.append({"rules": ids, "duration_ms": elapsed_ms})
             if out:
                 results.extend(out)
-        if error:
-            print(json.dumps(error))
-            sys.stdout.flush()
-            break
-        print(json.dumps({"type": "violations", "violations": results}))
+        if problem:
+            print(json.dumps(problem))
+        else:
+            print(json.dumps({"type": "violations", "violations": results, "timings": timings}))
         sys.stdout.flush()
 
 if __name__ == "__main__":
@@ -1,5 +1,18 @@
 from lib.cst_inline import Cst, byte_span_to_loc
 
+RULES = [
+    {
+        "id": "localparam_has_type",
+        "description": "localparams declare an explicit data type",
+        "rationale": (
+            "Untyped localparams take the type of their initial value, which can change "
+            "width or signedness when the expression changes."
+        ),
+        "default_severity": "warning",
+        "tags": ["style"],
+    },
+]
+
 def check(req):
     if req.get("stage") != "cst":
         return []
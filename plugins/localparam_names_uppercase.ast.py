@@ -1,5 +1,18 @@
 import re
 
+RULES = [
+    {
+        "id": "localparam_names_uppercase",
+        "description": "localparam names use UpperCamelCase or ALL_CAPS",
+        "rationale": (
+            "Capitalised constants are easy to tell apart from signals when reading "
+            "expressions."
+        ),
+        "default_severity": "warning",
+        "tags": ["naming"],
+    },
+]
+
 UPPER_CAMEL = re.compile(r"^[A-Z][A-Za-z0-9]*$")
 ALL_CAPS = re.compile(r"^[A-Z][A-Z0-9_]*$")
 
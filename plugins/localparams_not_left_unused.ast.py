@@ -1,5 +1,18 @@
 import re
 
+RULES = [
+    {
+        "id": "localparams_not_left_unused",
+        "description": "localparams are referenced after declaration",
+        "rationale": (
+            "Unused localparams are usually leftovers from refactoring and make readers "
+            "look for uses that do not exist."
+        ),
+        "default_severity": "warning",
+        "tags": ["unused"],
+    },
+]
+
 USED_WORD = re.compile(r"\bused\b", re.IGNORECASE)
 RESERVED_WORD = re.compile(r"\breserved\b", re.IGNORECASE)
 
@@ -2,6 +2,16 @@ import re
 
 from lib.utf8 import line_starts, point_to_loc
 
+RULES = [
+    {
+        "id": "macro_names_uppercase",
+        "description": "`define names use ALL_CAPS",
+        "rationale": "ALL_CAPS macro names make textual substitution obvious at the point of use.",
+        "default_severity": "warning",
+        "tags": ["naming", "preprocessor"],
+    },
+]
+
 DEFINE_RE = re.compile(r"(?m)^\s*`define\s+([A-Za-z_]\w*)")
 ALL_CAPS = re.compile(r"^[A-Z][A-Z0-9_]*$")
 
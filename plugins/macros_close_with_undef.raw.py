@@ -2,6 +2,19 @@ import re
 
 from lib.utf8 import line_starts, point_to_loc
 
+RULES = [
+    {
+        "id": "macros_close_with_undef",
+        "description": "macros defined in a file are `undef'd before its end",
+        "rationale": (
+            "Macros leak into every file compiled afterwards; undefining them keeps the "
+            "compilation order from changing behaviour."
+        ),
+        "default_severity": "warning",
+        "tags": ["preprocessor"],
+    },
+]
+
 def check(req):
     if req.get("stage") != "raw_text":
         return []
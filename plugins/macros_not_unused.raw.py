@@ -2,6 +2,19 @@ import re
 
 from lib.utf8 import line_starts, point_to_loc
 
+RULES = [
+    {
+        "id": "macros_not_unused",
+        "description": "macros defined in a file are used",
+        "rationale": (
+            "Unused macros are usually leftovers and still pollute the global macro "
+            "namespace."
+        ),
+        "default_severity": "warning",
+        "tags": ["unused", "preprocessor"],
+    },
+]
+
 DEFINE_PATTERN = re.compile(r"(?m)^\s*`define\s+([A-Za-z_]\w*)")
 USE_PATTERN = re.compile(r"`([A-Za-z_]\w*)")
 USED_WORD = re.compile(r"\bused\b", re.IGNORECASE)
@@ -2,6 +2,19 @@ import re
 
 from lib.utf8 import line_starts, point_to_loc
 
+RULES = [
+    {
+        "id": "macros_use_module_prefix",
+        "description": "macros defined inside a module are prefixed with the module name",
+        "rationale": (
+            "Macros share one global namespace; a module prefix avoids collisions between"
+            " IPs that define the same helper."
+        ),
+        "default_severity": "warning",
+        "tags": ["naming", "preprocessor"],
+    },
+]
+
 def check(req):
     if req.get("stage") != "raw_text":
         return []
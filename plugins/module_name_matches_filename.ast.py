@@ -1,6 +1,19 @@
 import re
 from pathlib import Path
 
+RULES = [
+    {
+        "id": "module_name_matches_filename",
+        "description": "module and package names match the file name",
+        "rationale": (
+            "Tools and people locate a design unit by file name; a mismatch breaks "
+            "library searches and makes code hard to find."
+        ),
+        "default_severity": "warning",
+        "tags": ["naming", "structure"],
+    },
+]
+
 def check(req):
     if req.get("stage") != "ast":
         return []
@@ -1,3 +1,16 @@
+RULES = [
+    {
+        "id": "module_names_lower_snake",
+        "description": "module names use lower_snake_case",
+        "rationale": (
+            "A single naming style for modules keeps hierarchies readable and file names "
+            "predictable."
+        ),
+        "default_severity": "warning",
+        "tags": ["naming"],
+    },
+]
+
 def check(req):
     if req.get("stage") != "ast":
         return []
@@ -1,5 +1,18 @@
 import re
 
+RULES = [
+    {
+        "id": "net_names_lower_snake",
+        "description": "net names use lower_snake_case",
+        "rationale": (
+            "A single naming style for nets keeps signals distinguishable from types and "
+            "constants."
+        ),
+        "default_severity": "warning",
+        "tags": ["naming"],
+    },
+]
+
 LOWER_SNAKE_DOLLAR = re.compile(r"^[a-z][a-z0-9_$]*$")
 
 def check(req):
@@ -1,5 +1,18 @@
 import re
 
+RULES = [
+    {
+        "id": "nets_not_left_unused",
+        "description": "nets are read or driven after declaration",
+        "rationale": (
+            "Unused nets are usually wiring mistakes or dead logic; intentional ones "
+            "should say so in their name."
+        ),
+        "default_severity": "warning",
+        "tags": ["unused"],
+    },
+]
+
 USED_WORD = re.compile(r"\bused\b", re.IGNORECASE)
 RESERVED_WORD = re.compile(r"\breserved\b", re.IGNORECASE)
 
@@ -1,3 +1,16 @@
+RULES = [
+    {
+        "id": "one_module_per_file",
+        "description": "each file declares at most one module",
+        "rationale": (
+            "One module per file keeps file lists, library searches and incremental "
+            "compilation simple."
+        ),
+        "default_severity": "warning",
+        "tags": ["structure"],
+    },
+]
+
 def check(req):
     if req.get("stage") != "ast":
         return []
@@ -1,5 +1,18 @@
 from lib.cst_inline import Cst, byte_span_to_loc
 
+RULES = [
+    {
+        "id": "parameter_has_type",
+        "description": "parameters declare an explicit data type",
+        "rationale": (
+            "Untyped parameters take the type of whatever value is passed in, so "
+            "overrides can change width or signedness unexpectedly."
+        ),
+        "default_severity": "warning",
+        "tags": ["style"],
+    },
+]
+
 def check(req):
     if req.get("stage") != "cst":
         return []
@@ -1,3 +1,16 @@
+RULES = [
+    {
+        "id": "parameter_names_uppercase",
+        "description": "parameter names use UpperCamelCase",
+        "rationale": (
+            "Capitalised parameters are easy to tell apart from signals when reading "
+            "expressions."
+        ),
+        "default_severity": "warning",
+        "tags": ["naming"],
+    },
+]
+
 def check(req):
     if req.get("stage") != "ast":
         return []
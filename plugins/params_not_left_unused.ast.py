@@ -1,5 +1,18 @@
 import re
 
+RULES = [
+    {
+        "id": "params_not_left_unused",
+        "description": "parameters are referenced after declaration",
+        "rationale": (
+            "Unused parameters widen the module interface without affecting behaviour and"
+            " mislead integrators."
+        ),
+        "default_severity": "warning",
+        "tags": ["unused"],
+    },
+]
+
 USED_WORD = re.compile(r"\bused\b", re.IGNORECASE)
 RESERVED_WORD = re.compile(r"\breserved\b", re.IGNORECASE)
 
@@ -1,17 +1,54 @@
+RULE_ID = "port_names_have_direction_suffix"
+
+RULES = [
+    {
+        "id": RULE_ID,
+        "description": "port names end with a suffix matching their direction",
+        "rationale": (
+            "Direction suffixes show at every use site whether a signal is driven inside "
+            "or outside the module."
+        ),
+        "default_severity": "warning",
+        "tags": ["naming"],
+    },
+]
+
+DEFAULT_SUFFIXES = {
+    "input": ["_i", "_ni"],
+    "output": ["_o", "_no"],
+    "inout": ["_io", "_nio"],
+}
+
+_SUFFIX_LIST = {"type": "array", "items": {"type": "string"}}
+
+OPTIONS_SCHEMA = {
+    "type": "object",
+    "properties": {
+        "suffixes": {
+            "type": "object",
+            "properties": {
+                "input": _SUFFIX_LIST,
+                "output": _SUFFIX_LIST,
+                "inout": _SUFFIX_LIST,
+            },
+            "additionalProperties": False,
+        },
+    },
+    "additionalProperties": False,
+}
+
 def check(req):
     if req.get("stage") != "ast":
         return []
     payload = req.get("payload") or {}
     ports = payload.get("ports") or []
+    options = ((req.get("rules") or {}).get("options") or {}).get(RULE_ID) or {}
+    suffixes = dict(DEFAULT_SUFFIXES)
+    suffixes.update(options.get("suffixes") or {})
     out = []
-    suffixes = {
-        "input": ("_i", "_ni"),
-        "output": ("_o", "_no"),
-        "inout": ("_io", "_nio"),
-    }
     for port in ports:
         direction = (port.get("direction") or "").lower()
-        allowed = suffixes.get(direction)
+        allowed = tuple(suffixes.get(direction) or ())
         if not allowed:
             continue
         name = port.get("name") or ""
@@ -20,7 +57,7 @@ def check(req):
         loc = port.get("loc") or {"line": 1, "col": 1, "end_line": 1, "end_col": 1}
         out.append(
             {
-                "rule_id": "port_names_have_direction_suffix",
+                "rule_id": RULE_ID,
                 "severity": "warning",
                 "message": f"{name} must end with {' or '.join(allowed)}",
                 "location": loc,
@@ -1,3 +1,13 @@
+RULES = [
+    {
+        "id": "port_names_lower_snake",
+        "description": "port names use lower_snake_case",
+        "rationale": "A single naming style for ports keeps instantiations readable.",
+        "default_severity": "warning",
+        "tags": ["naming"],
+    },
+]
+
 def check(req):
     if req.get("stage") != "ast":
         return []
@@ -1,5 +1,18 @@
 import re
 
+RULES = [
+    {
+        "id": "ports_not_left_unused",
+        "description": "ports are used inside their module",
+        "rationale": (
+            "Unused ports are usually missing logic or dangling connections; intentional "
+            "ones should say so in their name."
+        ),
+        "default_severity": "warning",
+        "tags": ["unused"],
+    },
+]
+
 USED_WORD = re.compile(r"\bused\b", re.IGNORECASE)
 RESERVED_WORD = re.compile(r"\breserved\b", re.IGNORECASE)
 
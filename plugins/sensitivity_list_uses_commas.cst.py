@@ -1,5 +1,18 @@
 from lib.cst_inline import Cst, byte_span_to_loc
 
+RULES = [
+    {
+        "id": "sensitivity_list_uses_commas",
+        "description": "event lists separate events with ',' instead of 'or'",
+        "rationale": (
+            "Commas are the SystemVerilog-2005 event separator and avoid confusion with "
+            "the logical or operator."
+        ),
+        "default_severity": "warning",
+        "tags": ["style"],
+    },
+]
+
 def check(req):
     if req.get("stage") != "cst":
         return []
@@ -8,13 +21,16 @@ def check(req):
     cst = Cst(ir)
     tokens = ir.get("tokens") or []
     line_starts = ir.get("line_starts") or [0]
+    data = (ir.get("source_text") or "").encode("utf-8")
     out = []
     for node in cst.of_kind("AlwaysConstruct"):
         fields = node.get("fields") or {}
+        prev_tok = None
         for ev in fields.get("events") or []:
+            tok = ev.get("token")
+            prev, prev_tok = prev_tok, tok
             if (ev.get("separator") or "").lower() != "or":
                 continue
-            tok = ev.get("token")
             if tok is None or tok >= len(tokens):
                 continue
             start = tokens[tok].get("start")
@@ -22,12 +38,32 @@ def check(req):
             if start is None or end is None:
                 continue
             loc = byte_span_to_loc(start, end, line_starts)
-            out.append(
-                {
-                    "rule_id": "sensitivity_list_uses_commas",
-                    "severity": "warning",
-                    "message": "use ',' separators in sensitivity lists instead of 'or'",
-                    "location": loc,
-                }
-            )
+            violation = {
+                "rule_id": "sensitivity_list_uses_commas",
+                "severity": "warning",
+                "message": "use ',' separators in sensitivity lists instead of 'or'",
+                "location": loc,
+            }
+            fix = _comma_fix(data, tokens, prev, tok)
+            if fix:
+                violation["fixes"] = [fix]
+            out.append(violation)
     return out
+
+def _comma_fix(data, tokens, prev, tok):
+    if prev is None or not data:
+        return None
+    for idx in range(prev + 1, tok):
+        if (tokens[idx].get("text") or "").lower() != "or":
+            continue
+        start = tokens[idx].get("start")
+        end = tokens[idx].get("end")
+        if start is None or end is None:
+            return None
+        while start > 0 and data[start - 1 : start] in (b" ", b"\t"):
+            start -= 1
+        return {
+            "description": "replace 'or' with ','",
+            "edits": [{"start": start, "end": end, "replacement": ","}],
+        }
+    return None
@@ -1,5 +1,18 @@
 from lib.cst_inline import Cst, byte_span_to_loc
 
+RULES = [
+    {
+        "id": "tasks_args_have_direction",
+        "description": "task arguments declare a direction",
+        "rationale": (
+            "Arguments without a direction inherit the previous one, so adding or "
+            "reordering arguments can silently change their direction."
+        ),
+        "default_severity": "warning",
+        "tags": ["style"],
+    },
+]
+
 def check(req):
     if req.get("stage") != "cst":
         return []
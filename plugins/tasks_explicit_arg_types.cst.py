@@ -1,5 +1,18 @@
 from lib.cst_inline import Cst, byte_span_to_loc
 
+RULES = [
+    {
+        "id": "tasks_explicit_arg_types",
+        "description": "task arguments declare an explicit data type",
+        "rationale": (
+            "Arguments without a type default to a 1-bit logic, truncating wider values "
+            "without any warning."
+        ),
+        "default_severity": "warning",
+        "tags": ["style"],
+    },
+]
+
 def check(req):
     if req.get("stage") != "cst":
         return []
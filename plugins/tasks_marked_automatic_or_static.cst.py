@@ -1,5 +1,18 @@
 from lib.cst_inline import Cst, byte_span_to_loc
 
+RULES = [
+    {
+        "id": "tasks_marked_automatic_or_static",
+        "description": "tasks in modules/packages/interfaces declare automatic or static",
+        "rationale": (
+            "Tasks in modules are static by default, so concurrent calls from forked "
+            "processes share storage unless the lifetime is stated."
+        ),
+        "default_severity": "warning",
+        "tags": ["style"],
+    },
+]
+
 def check(req):
     if req.get("stage") != "cst":
         return []
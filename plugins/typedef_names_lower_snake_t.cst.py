@@ -2,6 +2,19 @@ import re
 
 from lib.utf8 import span_to_loc
 
+RULES = [
+    {
+        "id": "typedef_names_lower_snake_t",
+        "description": "typedef names use lower_snake_case and end with _t",
+        "rationale": (
+            "A consistent _t suffix makes user-defined types recognisable at their point "
+            "of use."
+        ),
+        "default_severity": "warning",
+        "tags": ["naming"],
+    },
+]
+
 TYPEDEF_RE = re.compile(
     r"typedef(?!\s+enum).*?\s+(?P<name>[A-Za-z_]\w*)\s*;", re.DOTALL
 )
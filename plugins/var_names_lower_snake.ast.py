@@ -1,5 +1,18 @@
 import re
 
+RULES = [
+    {
+        "id": "var_names_lower_snake",
+        "description": "variable names use lower_snake_case",
+        "rationale": (
+            "A single naming style for variables keeps signals distinguishable from types"
+            " and constants."
+        ),
+        "default_severity": "warning",
+        "tags": ["naming"],
+    },
+]
+
 LOWER_SNAKE_DOLLAR = re.compile(r"^[a-z][a-z0-9_$]*$")
 
 def check(req):
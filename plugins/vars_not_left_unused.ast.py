@@ -1,5 +1,15 @@
 import re
 
+RULES = [
+    {
+        "id": "vars_not_left_unused",
+        "description": "variables are read or written after declaration",
+        "rationale": "Unused variables are usually dead logic left over from refactoring.",
+        "default_severity": "warning",
+        "tags": ["unused"],
+    },
+]
+
 USED_WORD = re.compile(r"\bused\b", re.IGNORECASE)
 RESERVED_WORD = re.compile(r"\breserved\b", re.IGNORECASE)
 
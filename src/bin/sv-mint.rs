@@ -1,78 +1,484 @@
-use clap::Parser;
-use std::path::PathBuf;
+use anyhow::Result;
+use clap::{Parser, Subcommand};
+use std::collections::{HashMap, HashSet};
+use std::io::{self, IsTerminal, Write};
+use std::path::{Path, PathBuf};
 use std::process::ExitCode;
+use std::time::Duration;
 
-use sv_mint::config::{apply_rule_overrides, load_from_path};
-use sv_mint::core::pipeline::Pipeline;
+use sv_mint::config::{
+    apply_rule_overrides, discover_config, group_by_config, load_from_path, read_stdin, restrict_overrides_to_enabled,
+    Config, InputText, InputsConfig, OutputFormat, STDIN_INPUT,
+};
+use sv_mint::core::baseline::{Baseline, BaselineMode};
+use sv_mint::core::cache::{default_cache_dir, ResultCache};
+use sv_mint::core::changes::ChangedLines;
+use sv_mint::core::fix::FixMode;
+use sv_mint::core::pipeline::{Pipeline, RunSummary};
+use sv_mint::core::profile::ProfileFormat;
+use sv_mint::core::watch::{FileWatcher, POLL_INTERVAL_MS};
 use sv_mint::diag::logging::init as log_init;
-use sv_mint::errors::ConfigError;
+use sv_mint::filelist::{FileList, RelativeTo};
+use sv_mint::inputs::{expand_inputs, InputFilter};
+use sv_mint::lsp::Server;
+use sv_mint::output::reporter_for_all;
+use sv_mint::plugin::{describe_rules, rule_examples, RuleInfo};
 
 #[derive(Parser, Debug)]
 #[command(
     name = "sv-mint",
     version,
-    about = "SystemVerilog linter (Windows, sv-parser integrated)"
+    about = "SystemVerilog linter (Windows, sv-parser integrated)",
+    subcommand_negates_reqs = true
 )]
 struct Cli {
-    #[arg(long, value_name = "CONFIG")]
+    #[command(subcommand)]
+    command: Option<Cmd>,
+    #[arg(long, value_name = "CONFIG", global = true)]
     config: Option<PathBuf>,
-    #[arg(long, value_name = "RULE", value_delimiter = ',')]
+    #[arg(long, value_name = "RULE", value_delimiter = ',', global = true)]
     disable: Vec<String>,
-    #[arg(long, value_name = "RULE", value_delimiter = ',')]
+    #[arg(long, value_name = "RULE", value_delimiter = ',', global = true)]
     only: Vec<String>,
+    #[arg(long, value_name = "FORMAT")]
+    format: Option<OutputFormat>,
+    #[arg(long, value_name = "FILE", conflicts_with = "write_baseline")]
+    baseline: Option<PathBuf>,
+    #[arg(long, value_name = "FILE")]
+    write_baseline: Option<PathBuf>,
+    #[arg(long, conflicts_with = "fix_dry_run")]
+    fix: bool,
+    #[arg(long)]
+    fix_dry_run: bool,
+    #[arg(short = 'f', long = "file-list", value_name = "FILE")]
+    file_list: Vec<PathBuf>,
+    #[arg(short = 'F', long = "file-list-relative", value_name = "FILE")]
+    file_list_relative: Vec<PathBuf>,
+    #[arg(long, value_name = "REV", conflicts_with = "diff_file")]
+    diff_base: Option<String>,
+    #[arg(long, value_name = "FILE")]
+    diff_file: Option<PathBuf>,
+    #[arg(
+        long,
+        value_name = "FORMAT",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "table"
+    )]
+    profile: Option<ProfileFormat>,
+    #[arg(long, conflicts_with_all = ["fix", "fix_dry_run", "write_baseline", "profile"])]
+    watch: bool,
+    #[arg(long)]
+    no_cache: bool,
+    #[arg(long, value_name = "DIR", conflicts_with = "no_cache")]
+    cache_dir: Option<PathBuf>,
+    #[arg(long, value_name = "PATH")]
+    stdin_filename: Option<PathBuf>,
     #[arg(
         value_name = "INPUT",
-        num_args = 1..
+        num_args = 1..,
+        required_unless_present_any = ["file_list", "file_list_relative", "diff_base", "diff_file"]
     )]
     input: Vec<PathBuf>,
 }
 
+#[derive(Subcommand, Debug)]
+enum Cmd {
+    Rules {
+        #[arg(long)]
+        json: bool,
+    },
+    Explain {
+        #[arg(value_name = "RULE")]
+        rule: String,
+        #[arg(long)]
+        run: bool,
+    },
+    Lsp,
+}
+
 fn main() -> ExitCode {
     let cli = Cli::parse();
-
-    let (mut cfg, _) = match load_from_path(cli.config) {
-        Ok(pair) => pair,
+    match run(cli) {
+        Ok(code) => code,
         Err(e) => {
             eprintln!("{}", e);
-            return ExitCode::from(3);
+            ExitCode::from(3)
         }
+    }
+}
+
+fn run(cli: Cli) -> Result<ExitCode> {
+    match &cli.command {
+        Some(Cmd::Rules { json }) => return list_rules(&cli, *json),
+        Some(Cmd::Explain { rule, run }) => return explain_rule(&cli, rule, *run),
+        Some(Cmd::Lsp) => return serve_lsp(&cli),
+        None => {}
+    }
+    let mut lists = FileList::default();
+    let sources = cli
+        .file_list
+        .iter()
+        .map(|p| (p, RelativeTo::Cwd))
+        .chain(cli.file_list_relative.iter().map(|p| (p, RelativeTo::ListDir)));
+    for (path, relative_to) in sources {
+        lists.extend(FileList::load(path, relative_to)?);
+    }
+    let mut args = std::mem::take(&mut lists.inputs);
+    args.extend(cli.input.iter().cloned());
+    let stdin = stdin_input(&cli, &args)?;
+
+    let changed = match (&cli.diff_base, &cli.diff_file) {
+        (Some(rev), _) => Some(ChangedLines::from_git(rev)?),
+        (None, Some(path)) => Some(ChangedLines::from_file(path)?),
+        (None, None) => None,
     };
 
-    if let Err(e) = log_init(&cfg.logging) {
-        eprintln!("{}", e);
-        return ExitCode::from(3);
+    let mut configs = ConfigCache::new(&cli, &lists);
+    let mut groups = match (&stdin, &cli.config) {
+        (Some((path, _)), Some(config)) => vec![(Some(config.clone()), vec![path.clone()])],
+        (Some((path, _)), None) => group_by_config(vec![path.clone()]),
+        (None, Some(path)) => {
+            let key = Some(path.clone());
+            let cfg = configs.load(&key)?;
+            let inputs = match &changed {
+                Some(changed) if args.is_empty() => {
+                    let filter = InputFilter::new(&cfg.inputs)?;
+                    changed.files().into_iter().filter(|p| filter.selects(p)).collect()
+                }
+                Some(changed) => expand_inputs(&cfg.inputs, &args)?
+                    .into_iter()
+                    .filter(|p| changed.contains_file(p))
+                    .collect(),
+                None => expand_inputs(&cfg.inputs, &args)?,
+            };
+            vec![(key, inputs)]
+        }
+        (None, None) => discover_groups(&mut configs, &args, changed.as_ref())?,
+    };
+
+    if groups.is_empty() {
+        groups.push((None, Vec::new()));
     }
+    for (key, _) in &groups {
+        configs.load(key)?;
+    }
+    let configs = configs.loaded;
+    let groups: Vec<(&Config, Vec<PathBuf>)> = groups
+        .into_iter()
+        .map(|(key, inputs)| (&configs[&key], inputs))
+        .collect();
 
-    if let Err(e) = apply_rule_overrides(&mut cfg.rule, &cli.only, &cli.disable) {
-        eprintln!("{}", e);
-        return ExitCode::from(3);
+    if cli.fix_dry_run && groups.iter().any(|(cfg, _)| cfg.output.format != OutputFormat::Text) {
+        anyhow::bail!("--fix-dry-run only supports text output");
+    }
+    let (first, _) = &groups[0];
+    log_init(&first.logging)?;
+    let all: Vec<&Config> = groups.iter().map(|(cfg, _)| *cfg).collect();
+    let mut pipeline = Pipeline::with_reporter(first, reporter_for_all(&all)?);
+    if cli.fix {
+        pipeline = pipeline.with_fix_mode(FixMode::Apply);
+    } else if cli.fix_dry_run {
+        pipeline = pipeline.with_fix_mode(FixMode::DryRun);
+    }
+    if let Some(changed) = changed {
+        pipeline = pipeline.with_changed_lines(changed);
+    }
+    if cli.profile.is_some() {
+        pipeline = pipeline.with_profile();
+    }
+    if !cli.no_cache {
+        if let Some(dir) = cli.cache_dir.clone().or_else(default_cache_dir) {
+            pipeline = pipeline.with_cache(ResultCache::new(dir));
+        }
+    }
+    if let Some((path, text)) = stdin {
+        pipeline = pipeline.with_source(path, text);
+    }
+    if let Some(path) = cli.write_baseline {
+        pipeline = pipeline.with_baseline(BaselineMode::Write {
+            path,
+            baseline: Baseline::default(),
+        });
+    } else if let Some(path) = &cli.baseline {
+        let baseline = Baseline::load(path)?;
+        pipeline = pipeline.with_baseline(BaselineMode::Filter(baseline));
     }
 
-    let inputs = match gather_inputs(&cli.input) {
-        Ok(v) => v,
-        Err(e) => {
-            eprintln!("{}", e);
-            return ExitCode::from(3);
+    if cli.watch {
+        return watch_inputs(&pipeline, &groups);
+    }
+    let mut summary = RunSummary::default();
+    for (cfg, inputs) in &groups {
+        summary.merge(pipeline.for_config(cfg).run_inputs(inputs)?);
+    }
+    pipeline.finish(&summary)?;
+    if let (Some(format), Some(profile)) = (cli.profile, pipeline.profile()) {
+        let report = profile
+            .lock()
+            .map_err(|_| anyhow::anyhow!("profile lock poisoned"))?
+            .report();
+        match format {
+            ProfileFormat::Table => eprint!("{}", report.to_table()),
+            ProfileFormat::Json => eprintln!("{}", serde_json::to_string_pretty(&report)?),
         }
-    };
+    }
+    Ok(summary.exit_code())
+}
 
-    let pipeline = Pipeline::new(&cfg);
-    let summary = match pipeline.run_files(&inputs) {
-        Ok(s) => s,
-        Err(e) => {
-            eprintln!("{}", e);
-            return ExitCode::from(3);
+fn stdin_input(cli: &Cli, args: &[PathBuf]) -> Result<Option<(PathBuf, InputText)>> {
+    if !args.iter().any(|a| a.as_os_str() == STDIN_INPUT) {
+        if cli.stdin_filename.is_some() {
+            anyhow::bail!("--stdin-filename requires `{}` as an input", STDIN_INPUT);
+        }
+        return Ok(None);
+    }
+    if args.len() > 1 {
+        anyhow::bail!("`{}` cannot be combined with other inputs", STDIN_INPUT);
+    }
+    if cli.fix || cli.watch {
+        anyhow::bail!("--fix and --watch cannot be used with stdin input");
+    }
+    let path = cli.stdin_filename.clone().unwrap_or_else(|| PathBuf::from("<stdin>"));
+    let text = read_stdin(&path)?;
+    Ok(Some((path, text)))
+}
+
+fn watch_inputs(pipeline: &Pipeline, groups: &[(&Config, Vec<PathBuf>)]) -> Result<ExitCode> {
+    if groups.iter().any(|(cfg, _)| cfg.output.format != OutputFormat::Text) {
+        anyhow::bail!("--watch only supports text output");
+    }
+    let mut watcher = FileWatcher::default();
+    let mut owner: HashMap<PathBuf, usize> = HashMap::new();
+    let mut hosts = Vec::with_capacity(groups.len());
+    for (idx, (cfg, inputs)) in groups.iter().enumerate() {
+        hosts.push(pipeline.for_config(cfg).start_host()?);
+        for input in inputs {
+            watcher.track(input, &cfg.svparser);
+            owner.insert(input.clone(), idx);
+        }
+    }
+    let mut results: HashMap<PathBuf, (usize, bool)> = HashMap::new();
+    let mut pending: Vec<PathBuf> = groups.iter().flat_map(|(_, inputs)| inputs.iter().cloned()).collect();
+    loop {
+        if !pending.is_empty() {
+            if io::stdout().is_terminal() {
+                write!(io::stdout(), "\x1b[2J\x1b[H")?;
+            }
+            for (idx, (cfg, _)) in groups.iter().enumerate() {
+                let group = pipeline.for_config(cfg);
+                for path in pending.iter().filter(|p| owner.get(*p) == Some(&idx)) {
+                    let summary = group.run_batch_with_host(std::slice::from_ref(path), hosts[idx].as_mut());
+                    results.insert(path.clone(), (summary.violations, summary.had_error));
+                }
+            }
+            let violations: usize = results.values().map(|(n, _)| n).sum();
+            let dirty = results.values().filter(|(n, _)| *n > 0).count();
+            let errors = results.values().filter(|(_, failed)| *failed).count();
+            let mut line = format!(
+                "[watch] re-linted {} file(s): {} violation(s) in {} of {} file(s)",
+                pending.len(),
+                violations,
+                dirty,
+                watcher.inputs()
+            );
+            if errors > 0 {
+                line.push_str(&format!(", {} failed", errors));
+            }
+            writeln!(io::stdout(), "{}; waiting for changes...", line)?;
+            io::stdout().flush()?;
         }
+        std::thread::sleep(Duration::from_millis(POLL_INTERVAL_MS));
+        pending = watcher.poll();
+    }
+}
+
+fn serve_lsp(cli: &Cli) -> Result<ExitCode> {
+    let lists = FileList::default();
+    let load = |key: &Option<PathBuf>| load_config(cli, &lists, key);
+    let mut server = Server::new(io::stdout().lock(), cli.config.clone(), Box::new(load));
+    let clean = server.run(io::stdin().lock())?;
+    Ok(ExitCode::from(if clean { 0 } else { 1 }))
+}
+
+fn list_rules(cli: &Cli, json: bool) -> Result<ExitCode> {
+    let lists = FileList::default();
+    let mut configs = ConfigCache::new(cli, &lists);
+    let cfg = configs.load(&cli.config)?;
+    log_init(&cfg.logging)?;
+    let rules = describe_rules(cfg)?;
+    if json {
+        println!("{}", serde_json::to_string_pretty(&rules)?);
+    } else {
+        print_rules(&rules);
+    }
+    Ok(ExitCode::from(0))
+}
+
+fn print_rules(rules: &[RuleInfo]) {
+    let scripts: Vec<String> = rules.iter().map(|r| display_path(Path::new(&r.script))).collect();
+    let id_w = rules.iter().map(|r| r.id.len()).chain([2]).max().unwrap_or(2);
+    let stage_w = rules.iter().map(|r| r.stage.len()).chain([5]).max().unwrap_or(5);
+    let script_w = scripts.iter().map(String::len).chain([6]).max().unwrap_or(6);
+    println!(
+        "{:<id_w$}  {:<stage_w$}  {:<7}  {:<script_w$}  DESCRIPTION",
+        "ID", "STAGE", "ENABLED", "SCRIPT"
+    );
+    for (rule, script) in rules.iter().zip(&scripts) {
+        let enabled = if rule.enabled { "yes" } else { "no" };
+        let description = rule.description.as_deref().unwrap_or("-");
+        println!(
+            "{:<id_w$}  {:<stage_w$}  {:<7}  {:<script_w$}  {}",
+            rule.id, rule.stage, enabled, script, description
+        );
+    }
+}
+
+fn explain_rule(cli: &Cli, id: &str, run: bool) -> Result<ExitCode> {
+    let lists = FileList::default();
+    let mut configs = ConfigCache::new(cli, &lists);
+    configs.load(&cli.config)?;
+    let mut cfg = configs.loaded.remove(&cli.config).expect("config loaded above");
+    log_init(&cfg.logging)?;
+    let rules = describe_rules(&cfg)?;
+    let Some(rule) = rules.iter().find(|r| r.id == id) else {
+        anyhow::bail!("rule {} not found", id);
     };
+    let enabled = if rule.enabled { "enabled" } else { "disabled" };
+    println!("{} ({} stage, {})", rule.id, rule.stage, enabled);
+    println!("script: {}", display_path(Path::new(&rule.script)));
+    if let Some(severity) = &rule.severity {
+        println!("severity: {}", severity);
+    }
+    if !rule.tags.is_empty() {
+        println!("tags: {}", rule.tags.join(", "));
+    }
+    if let Some(url) = &rule.docs_url {
+        println!("docs: {}", url);
+    }
+    if let Some(description) = &rule.description {
+        println!("\n{}", description);
+    }
+    if let Some(rationale) = &rule.rationale {
+        println!("\nWhy: {}", rationale);
+    }
+    let examples = rule_examples(&cfg, rule);
+    for example in &examples {
+        let label = if example.good { "Good" } else { "Bad" };
+        println!("\n{} ({}):", label, display_path(&example.path));
+        for line in example.source.trim_end().lines() {
+            if line.is_empty() {
+                println!();
+            } else {
+                println!("    {}", line);
+            }
+        }
+    }
+    if run {
+        let bad: Vec<PathBuf> = examples
+            .iter()
+            .filter(|e| !e.good)
+            .map(|e| PathBuf::from(display_path(&e.path)))
+            .collect();
+        if bad.is_empty() {
+            println!("\nNo bad examples to run.");
+            return Ok(ExitCode::from(0));
+        }
+        println!("\nOutput on bad examples:");
+        apply_rule_overrides(&mut cfg.rule, &[id.to_string()], &[])?;
+        restrict_overrides_to_enabled(&mut cfg.overrides, &cfg.rule);
+        cfg.output.format = OutputFormat::Text;
+        Pipeline::new(&cfg).run_files(&bad)?;
+    }
+    Ok(ExitCode::from(0))
+}
 
-    summary.exit_code()
+fn display_path(path: &Path) -> String {
+    let cwd = std::env::current_dir().unwrap_or_default();
+    path.strip_prefix(&cwd).unwrap_or(path).display().to_string()
 }
 
-fn gather_inputs(direct: &[PathBuf]) -> Result<Vec<PathBuf>, ConfigError> {
-    if direct.is_empty() {
-        return Err(ConfigError::InvalidValue {
-            detail: "no input files provided".to_string(),
-        });
+fn discover_groups(
+    configs: &mut ConfigCache,
+    args: &[PathBuf],
+    changed: Option<&ChangedLines>,
+) -> Result<Vec<(Option<PathBuf>, Vec<PathBuf>)>> {
+    if let (Some(changed), true) = (changed, args.is_empty()) {
+        let mut selected = Vec::new();
+        for (key, candidates) in group_by_config(changed.files()) {
+            let filter = InputFilter::new(&configs.load(&key)?.inputs)?;
+            selected.extend(candidates.into_iter().filter(|p| filter.selects(p)));
+        }
+        return Ok(group_by_config(selected));
+    }
+    let mut files = Vec::new();
+    for (key, anchored) in group_by_config(args.to_vec()) {
+        let inputs = match &key {
+            Some(_) => expand_inputs(&configs.load(&key)?.inputs, &anchored)?,
+            None => expand_inputs(&InputsConfig::default(), &anchored)?,
+        };
+        files.extend(inputs.into_iter().map(|p| (key.clone(), p)));
+    }
+    let mut seen = HashSet::new();
+    let mut filters: HashMap<Option<PathBuf>, InputFilter> = HashMap::new();
+    let mut selected = Vec::new();
+    for (anchor, path) in files {
+        if !seen.insert(path.clone()) {
+            continue;
+        }
+        if changed.is_some_and(|c| !c.contains_file(&path)) {
+            continue;
+        }
+        let nearest = discover_config(&path);
+        let explicit = args.contains(&path);
+        if nearest != anchor && nearest.is_some() && !explicit {
+            if !filters.contains_key(&nearest) {
+                let filter = InputFilter::new(&configs.load(&nearest)?.inputs)?;
+                filters.insert(nearest.clone(), filter);
+            }
+            if !filters[&nearest].selects(&path) {
+                continue;
+            }
+        }
+        selected.push(path);
+    }
+    Ok(group_by_config(selected))
+}
+
+struct ConfigCache<'c> {
+    cli: &'c Cli,
+    lists: &'c FileList,
+    loaded: HashMap<Option<PathBuf>, Config>,
+}
+
+impl<'c> ConfigCache<'c> {
+    fn new(cli: &'c Cli, lists: &'c FileList) -> Self {
+        Self {
+            cli,
+            lists,
+            loaded: HashMap::new(),
+        }
+    }
+
+    fn load(&mut self, key: &Option<PathBuf>) -> Result<&Config> {
+        if !self.loaded.contains_key(key) {
+            let cfg = load_config(self.cli, self.lists, key)?;
+            self.loaded.insert(key.clone(), cfg);
+        }
+        Ok(&self.loaded[key])
+    }
+}
+
+fn load_config(cli: &Cli, lists: &FileList, key: &Option<PathBuf>) -> Result<Config> {
+    let (mut cfg, _) = load_from_path(key.clone())?;
+    apply_rule_overrides(&mut cfg.rule, &cli.only, &cli.disable)?;
+    if !cli.only.is_empty() || !cli.disable.is_empty() {
+        restrict_overrides_to_enabled(&mut cfg.overrides, &cfg.rule);
+    }
+    if let Some(format) = cli.format {
+        cfg.output.format = format;
     }
-    Ok(direct.to_vec())
+    cfg.svparser.include_paths.extend(lists.include_paths.iter().cloned());
+    cfg.svparser.defines.extend(lists.defines.iter().cloned());
+    Ok(cfg)
 }
@@ -0,0 +1,306 @@
+use crate::errors::ConfigError;
+use crate::textutil::{normalize_lf, strip_bom, ContentHasher};
+use crate::types::Violation;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::env;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+const BASELINE_VERSION: u32 = 1;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Fingerprint {
+    pub rule_id: String,
+    pub file: String,
+    pub line_hash: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct BaselineEntry {
+    #[serde(flatten)]
+    fingerprint: Fingerprint,
+    count: usize,
+}
+
+#[derive(Serialize, Deserialize)]
+struct BaselineFile {
+    version: u32,
+    violations: Vec<BaselineEntry>,
+}
+
+#[derive(Default)]
+pub struct Baseline {
+    entries: BTreeMap<Fingerprint, usize>,
+}
+
+impl Baseline {
+    pub fn load(path: &Path) -> Result<Self, ConfigError> {
+        let text = fs::read_to_string(path).map_err(|e| ConfigError::IoFailed {
+            detail: format!("{} ({})", path.display(), e),
+        })?;
+        let file: BaselineFile = serde_json::from_str(&text).map_err(|e| ConfigError::InvalidValue {
+            detail: format!("baseline {}: {}", path.display(), e),
+        })?;
+        if file.version != BASELINE_VERSION {
+            return Err(ConfigError::InvalidValue {
+                detail: format!(
+                    "baseline {} has unsupported version {} (expected {})",
+                    path.display(),
+                    file.version,
+                    BASELINE_VERSION
+                ),
+            });
+        }
+        let mut entries = BTreeMap::new();
+        for entry in file.violations {
+            *entries.entry(entry.fingerprint).or_insert(0) += entry.count;
+        }
+        Ok(Self { entries })
+    }
+
+    pub fn write(&self, path: &Path) -> Result<(), ConfigError> {
+        let file = BaselineFile {
+            version: BASELINE_VERSION,
+            violations: self
+                .entries
+                .iter()
+                .map(|(fingerprint, count)| BaselineEntry {
+                    fingerprint: fingerprint.clone(),
+                    count: *count,
+                })
+                .collect(),
+        };
+        let mut text = serde_json::to_string_pretty(&file).map_err(|e| ConfigError::InvalidValue {
+            detail: format!("baseline {}: {}", path.display(), e),
+        })?;
+        text.push('\n');
+        fs::write(path, text).map_err(|e| ConfigError::IoFailed {
+            detail: format!("{} ({})", path.display(), e),
+        })
+    }
+
+    pub fn record(&mut self, fingerprint: Fingerprint) {
+        *self.entries.entry(fingerprint).or_insert(0) += 1;
+    }
+
+    pub fn take(&mut self, fingerprint: &Fingerprint) -> bool {
+        match self.entries.get_mut(fingerprint) {
+            Some(count) if *count > 0 => {
+                *count -= 1;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+pub enum BaselineMode {
+    Filter(Baseline),
+    Write { path: PathBuf, baseline: Baseline },
+}
+
+impl BaselineMode {
+    /// Fingerprints are keyed by paths relative to `root`, the anchor of the file's config.
+    pub fn apply(&mut self, root: &Path, input_path: &Path, text: &str, violations: Vec<Violation>) -> Vec<Violation> {
+        let root = absolute(root);
+        let mut lines = LineSource::new(&root, input_path, text);
+        match self {
+            BaselineMode::Filter(baseline) => violations
+                .into_iter()
+                .filter(|v| !baseline.take(&lines.fingerprint(v)))
+                .collect(),
+            BaselineMode::Write { baseline, .. } => {
+                for v in &violations {
+                    baseline.record(lines.fingerprint(v));
+                }
+                Vec::new()
+            }
+        }
+    }
+
+    pub fn finish(&self) -> Result<(), ConfigError> {
+        match self {
+            BaselineMode::Filter(_) => Ok(()),
+            BaselineMode::Write { path, baseline } => baseline.write(path),
+        }
+    }
+}
+
+struct LineSource<'a> {
+    root: &'a Path,
+    input_key: String,
+    text: &'a str,
+    others: HashMap<String, String>,
+}
+
+impl<'a> LineSource<'a> {
+    fn new(root: &'a Path, input_path: &Path, text: &'a str) -> Self {
+        Self {
+            root,
+            input_key: path_key(root, input_path),
+            text,
+            others: HashMap::new(),
+        }
+    }
+
+    fn fingerprint(&mut self, v: &Violation) -> Fingerprint {
+        let file = match v.location.file.as_deref() {
+            Some(file) => path_key(self.root, Path::new(file)),
+            None => self.input_key.clone(),
+        };
+        let line = v.location.line.max(1) as usize - 1;
+        let content = if file == self.input_key {
+            self.text.lines().nth(line).unwrap_or("")
+        } else {
+            let root = self.root;
+            let other = self.others.entry(file.clone()).or_insert_with(|| {
+                fs::read_to_string(root.join(&file))
+                    .map(|s| normalize_lf(strip_bom(s)))
+                    .unwrap_or_default()
+            });
+            other.lines().nth(line).unwrap_or("")
+        };
+        Fingerprint {
+            rule_id: v.rule_id.clone(),
+            file,
+            line_hash: line_hash(content),
+        }
+    }
+}
+
+pub fn anchor_dir(start: &Path) -> PathBuf {
+    let start = absolute(start);
+    let mut dir = start.as_path();
+    loop {
+        if dir.join(".git").exists() {
+            return dir.to_path_buf();
+        }
+        match dir.parent() {
+            Some(parent) => dir = parent,
+            None => return start,
+        }
+    }
+}
+
+fn path_key(root: &Path, path: &Path) -> String {
+    let path = absolute(path);
+    let key = path.strip_prefix(root).unwrap_or(&path);
+    key.to_string_lossy().replace('\\', "/")
+}
+
+fn absolute(path: &Path) -> PathBuf {
+    let joined = match env::current_dir() {
+        Ok(cwd) if path.is_relative() => cwd.join(path),
+        _ => path.to_path_buf(),
+    };
+    let mut out = PathBuf::new();
+    for component in joined.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                out.pop();
+            }
+            other => out.push(other),
+        }
+    }
+    out
+}
+
+pub fn line_hash(line: &str) -> String {
+    let mut hasher = ContentHasher::new();
+    for (i, word) in line.split_whitespace().enumerate() {
+        if i > 0 {
+            hasher.update(b" ");
+        }
+        hasher.update(word.as_bytes());
+    }
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Location, Severity};
+    use tempfile::tempdir;
+
+    fn violation(rule_id: &str, line: u32) -> Violation {
+        Violation {
+            rule_id: rule_id.to_string(),
+            severity: Severity::Warning,
+            message: String::new(),
+            location: Location {
+                line,
+                col: 1,
+                end_line: line,
+                end_col: 2,
+                file: None,
+            },
+            fixes: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn line_hash_ignores_whitespace_changes() {
+        assert_eq!(line_hash("  wire   a ;"), line_hash("wire a ;\t"));
+        assert_ne!(line_hash("wire a;"), line_hash("wire b;"));
+    }
+
+    #[test]
+    fn baseline_survives_unrelated_line_shifts() {
+        let tmp = tempdir().unwrap();
+        let path = tmp.path().join("baseline.json");
+        let before = "module m;\n  wire Bad;\nendmodule\n";
+        let mut mode = BaselineMode::Write {
+            path: path.clone(),
+            baseline: Baseline::default(),
+        };
+        let root = Path::new(".");
+        let kept = mode.apply(root, Path::new("m.sv"), before, vec![violation("r", 2)]);
+        assert!(kept.is_empty());
+        mode.finish().unwrap();
+
+        let after = "// header\nmodule m;\n    wire Bad;\n  wire Worse;\nendmodule\n";
+        let mut mode = BaselineMode::Filter(Baseline::load(&path).unwrap());
+        let kept = mode.apply(
+            root,
+            Path::new("m.sv"),
+            after,
+            vec![violation("r", 3), violation("r", 4), violation("other", 3)],
+        );
+        let kept: Vec<_> = kept.iter().map(|v| (v.rule_id.as_str(), v.location.line)).collect();
+        assert_eq!(kept, vec![("r", 4), ("other", 3)]);
+    }
+
+    #[test]
+    fn duplicate_lines_are_counted() {
+        let text = "x;\nx;\nx;\n";
+        let mut baseline = Baseline::default();
+        let root = absolute(Path::new("."));
+        let mut lines = LineSource::new(&root, Path::new("a.sv"), text);
+        baseline.record(lines.fingerprint(&violation("r", 1)));
+        let mut mode = BaselineMode::Filter(baseline);
+        let kept = mode.apply(
+            &root,
+            Path::new("a.sv"),
+            text,
+            vec![violation("r", 1), violation("r", 2)],
+        );
+        assert_eq!(kept.len(), 1);
+    }
+
+    #[test]
+    fn fingerprints_are_relative_to_the_root() {
+        let tmp = tempdir().unwrap();
+        let root = tmp.path();
+        let text = "wire Bad;\n";
+        let mut baseline = Baseline::default();
+        let mut lines = LineSource::new(root, &root.join("rtl/a.sv"), text);
+        let fingerprint = lines.fingerprint(&violation("r", 1));
+        assert_eq!(fingerprint.file, "rtl/a.sv");
+        baseline.record(fingerprint);
+        let mut mode = BaselineMode::Filter(baseline);
+        let kept = mode.apply(root, &root.join("./rtl/../rtl/a.sv"), text, vec![violation("r", 1)]);
+        assert!(kept.is_empty());
+    }
+}
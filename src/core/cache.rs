@@ -0,0 +1,265 @@
+use crate::config::{Config, RuleConfig};
+use crate::plugin_scripts::resolve_script_path;
+use crate::sv::includes::resolve_includes;
+use crate::textutil::{content_hash, ContentHasher};
+use crate::types::Violation;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime};
+
+const FORMAT_VERSION: u32 = 2;
+const MAX_AGE: Duration = Duration::from_secs(30 * 24 * 60 * 60);
+const MAX_ENTRIES: usize = 10_000;
+
+pub fn default_cache_dir() -> Option<PathBuf> {
+    let base = env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .filter(|p| p.is_absolute())
+        .or_else(|| env::var_os("LOCALAPPDATA").map(PathBuf::from))
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))?;
+    Some(base.join("sv-mint"))
+}
+
+#[derive(Serialize, Deserialize)]
+struct CacheEntry {
+    version: u32,
+    violations: Vec<Violation>,
+}
+
+pub struct ResultCache {
+    dir: PathBuf,
+    files: Mutex<HashMap<PathBuf, (FileStamp, String)>>,
+}
+
+type FileStamp = Option<(SystemTime, u64)>;
+
+impl ResultCache {
+    pub fn new(dir: PathBuf) -> Self {
+        Self {
+            dir,
+            files: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn key(&self, cfg: &Config, rules: &[RuleConfig], path: &Path, text: &str) -> String {
+        let mut h = ContentHasher::new();
+        h.field(env!("CARGO_PKG_VERSION"));
+        h.field(&FORMAT_VERSION.to_string());
+        h.field(&path.to_string_lossy());
+        h.field(&content_hash(text.as_bytes()));
+        h.field(&self.includes_hash(cfg, path, text));
+        h.field(&self.config_hash(cfg, rules));
+        h.finish()
+    }
+
+    pub fn load(&self, key: &str) -> Option<Vec<Violation>> {
+        let path = self.entry_path(key);
+        let data = fs::read(&path).ok()?;
+        let entry: CacheEntry = serde_json::from_slice(&data).ok()?;
+        if entry.version != FORMAT_VERSION {
+            return None;
+        }
+        if let Ok(file) = fs::File::options().write(true).open(&path) {
+            let _ = file.set_modified(SystemTime::now());
+        }
+        Some(entry.violations)
+    }
+
+    pub fn store(&self, key: &str, violations: &[Violation]) {
+        let path = self.entry_path(key);
+        let Some(parent) = path.parent() else { return };
+        if fs::create_dir_all(parent).is_err() {
+            return;
+        }
+        let entry = CacheEntry {
+            version: FORMAT_VERSION,
+            violations: violations.to_vec(),
+        };
+        let Ok(data) = serde_json::to_vec(&entry) else { return };
+        let tmp = path.with_extension(format!("tmp{}", std::process::id()));
+        if fs::write(&tmp, data).is_ok() && fs::rename(&tmp, &path).is_err() {
+            let _ = fs::remove_file(&tmp);
+        }
+    }
+
+    pub fn prune(&self) -> usize {
+        let now = SystemTime::now();
+        let mut entries: Vec<(SystemTime, PathBuf)> = Vec::new();
+        let mut removed = 0;
+        let Ok(shards) = fs::read_dir(&self.dir) else { return 0 };
+        for shard in shards.flatten() {
+            let Ok(files) = fs::read_dir(shard.path()) else {
+                continue;
+            };
+            for file in files.flatten() {
+                let path = file.path();
+                let modified = file
+                    .metadata()
+                    .and_then(|m| m.modified())
+                    .unwrap_or(SystemTime::UNIX_EPOCH);
+                let expired = now.duration_since(modified).is_ok_and(|age| age > MAX_AGE);
+                if expired || path.extension().is_none_or(|ext| ext != "json") {
+                    if fs::remove_file(&path).is_ok() {
+                        removed += 1;
+                    }
+                } else {
+                    entries.push((modified, path));
+                }
+            }
+        }
+        if entries.len() > MAX_ENTRIES {
+            entries.sort();
+            let excess = entries.len() - MAX_ENTRIES;
+            for (_, path) in entries.into_iter().take(excess) {
+                if fs::remove_file(&path).is_ok() {
+                    removed += 1;
+                }
+            }
+        }
+        removed
+    }
+
+    fn entry_path(&self, key: &str) -> PathBuf {
+        self.dir.join(&key[..2]).join(format!("{key}.json"))
+    }
+
+    fn file_hash(&self, path: &Path) -> String {
+        let stamp = fs::metadata(path)
+            .ok()
+            .and_then(|m| Some((m.modified().ok()?, m.len())));
+        if let Ok(files) = self.files.lock() {
+            if let Some((seen, hash)) = files.get(path) {
+                if stamp.is_some() && *seen == stamp {
+                    return hash.clone();
+                }
+            }
+        }
+        let hash = match fs::read(path) {
+            Ok(data) => content_hash(&data),
+            Err(_) => "missing".to_string(),
+        };
+        if let Ok(mut files) = self.files.lock() {
+            files.insert(path.to_path_buf(), (stamp, hash.clone()));
+        }
+        hash
+    }
+
+    fn config_hash(&self, cfg: &Config, rules: &[RuleConfig]) -> String {
+        let mut h = ContentHasher::new();
+        for stage in &cfg.stages.enabled {
+            h.field(stage.as_str());
+        }
+        h.field("required");
+        for stage in &cfg.stages.required {
+            h.field(stage.as_str());
+        }
+        h.field(&serde_json::to_string(&cfg.svparser).unwrap_or_default());
+        let t = &cfg.transport;
+        h.field(&format!(
+            "{} {} {} {:?} {}",
+            t.max_request_bytes, t.warn_margin_bytes, t.max_response_bytes, t.on_exceed, t.fail_ci_on_skip
+        ));
+        h.field(&format!("{:?}", cfg.plugin.on_rule_crash));
+        for rule in rules {
+            h.field(&rule.id);
+            h.field(&format!(
+                "{} {} {:?} {:?}",
+                rule.enabled,
+                rule.native,
+                rule.stage.map(|s| s.as_str()),
+                rule.severity
+            ));
+            h.field(&serde_json::to_string(&rule.options).unwrap_or_default());
+            if rule.native || !rule.enabled {
+                continue;
+            }
+            let script = resolve_script_path(cfg, &rule.script);
+            h.field(&script);
+            h.field(&self.file_hash(Path::new(&script)));
+            h.field(rule.host.as_deref().unwrap_or(""));
+        }
+        let hosts: BTreeSet<Option<&str>> = rules
+            .iter()
+            .filter(|r| r.enabled && !r.native)
+            .map(|r| r.host.as_deref())
+            .collect();
+        for host in hosts {
+            match host.and_then(|name| cfg.plugin.hosts.get(name)) {
+                Some(command) => {
+                    h.field(&command.cmd);
+                    for arg in &command.args {
+                        h.field(arg);
+                        if Path::new(arg).is_file() {
+                            h.field(&self.file_hash(Path::new(arg)));
+                        }
+                    }
+                }
+                None => {
+                    h.field(&cfg.plugin.cmd);
+                    let host_script = PathBuf::from(resolve_script_path(cfg, "plugins/lib/rule_host.py"));
+                    for lib in sorted_dir(host_script.parent()) {
+                        h.field(&lib.to_string_lossy());
+                        h.field(&self.file_hash(&lib));
+                    }
+                }
+            }
+        }
+        h.finish()
+    }
+
+    fn includes_hash(&self, cfg: &Config, path: &Path, text: &str) -> String {
+        let mut h = ContentHasher::new();
+        if cfg.svparser.ignore_include {
+            return h.finish();
+        }
+        for include in resolve_includes(path, text, &cfg.svparser.include_paths) {
+            match include.path {
+                Some(found) => {
+                    h.field(&found.to_string_lossy());
+                    h.field(&self.file_hash(&found));
+                }
+                None => h.field(&format!("missing:{}", include.name)),
+            }
+        }
+        h.finish()
+    }
+}
+
+fn sorted_dir(dir: Option<&Path>) -> Vec<PathBuf> {
+    let Some(Ok(entries)) = dir.map(fs::read_dir) else {
+        return Vec::new();
+    };
+    let mut files: Vec<PathBuf> = entries
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| p.extension().is_some_and(|ext| ext == "py"))
+        .collect();
+    files.sort();
+    files
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entries_round_trip_and_prune_stray_files() {
+        let tmp = tempfile::tempdir().unwrap();
+        let cache = ResultCache::new(tmp.path().to_path_buf());
+        let key = content_hash(b"module top; endmodule");
+        assert!(cache.load(&key).is_none());
+        cache.store(&key, &[]);
+        assert_eq!(cache.load(&key).map(|v| v.len()), Some(0));
+        fs::write(tmp.path().join(&key[..2]).join("stale.tmp1"), "").unwrap();
+        assert_eq!(cache.prune(), 1);
+        assert!(cache.load(&key).is_some());
+    }
+}
@@ -0,0 +1,246 @@
+use crate::errors::ConfigError;
+use crate::types::Violation;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+#[derive(Default, Debug)]
+pub struct ChangedLines {
+    files: HashMap<PathBuf, Vec<(u32, u32)>>,
+}
+
+impl ChangedLines {
+    pub fn from_git(rev: &str) -> Result<Self, ConfigError> {
+        let toplevel = git(&["rev-parse", "--show-toplevel"])?;
+        let root = PathBuf::from(toplevel.trim());
+        let diff = git(&[
+            "diff",
+            "--no-color",
+            "--no-ext-diff",
+            "--unified=0",
+            "--src-prefix=a/",
+            "--dst-prefix=b/",
+            rev,
+            "--",
+        ])?;
+        Ok(Self::parse(&diff, &root))
+    }
+
+    pub fn from_file(path: &Path) -> Result<Self, ConfigError> {
+        let text = fs::read_to_string(path).map_err(|e| ConfigError::IoFailed {
+            detail: format!("{} ({})", path.display(), e),
+        })?;
+        let root = git(&["rev-parse", "--show-toplevel"])
+            .map(|toplevel| PathBuf::from(toplevel.trim()))
+            .unwrap_or_default();
+        Ok(Self::parse(&text, &root))
+    }
+
+    pub fn parse(diff: &str, root: &Path) -> Self {
+        let mut files: HashMap<PathBuf, Vec<(u32, u32)>> = HashMap::new();
+        let mut strip_prefix = false;
+        let mut current: Option<PathBuf> = None;
+        let mut next_line = 0u32;
+        let (mut old_left, mut new_left) = (0u32, 0u32);
+        for line in diff.lines() {
+            if old_left == 0 && new_left == 0 {
+                if let Some(old) = line.strip_prefix("--- ") {
+                    strip_prefix = old.starts_with("a/");
+                    current = None;
+                    continue;
+                }
+                if let Some(new) = line.strip_prefix("+++ ") {
+                    let name = new.split('\t').next().unwrap_or(new).trim_end();
+                    current = if name == "/dev/null" {
+                        None
+                    } else {
+                        let name = if strip_prefix {
+                            name.strip_prefix("b/").unwrap_or(name)
+                        } else {
+                            name
+                        };
+                        let path = path_key(&root.join(name));
+                        files.entry(path.clone()).or_default();
+                        Some(path)
+                    };
+                    continue;
+                }
+                if let Some((start, old_count, new_count)) = parse_hunk_header(line) {
+                    next_line = start;
+                    old_left = old_count;
+                    new_left = new_count;
+                }
+                continue;
+            }
+            match line.as_bytes().first() {
+                Some(b'+') => {
+                    if let Some(path) = current.as_ref() {
+                        let ranges = files.entry(path.clone()).or_default();
+                        match ranges.last_mut() {
+                            Some(last) if last.1 + 1 == next_line => last.1 = next_line,
+                            _ => ranges.push((next_line, next_line)),
+                        }
+                    }
+                    next_line += 1;
+                    new_left = new_left.saturating_sub(1);
+                }
+                Some(b'-') => old_left = old_left.saturating_sub(1),
+                Some(b'\\') => {}
+                _ => {
+                    next_line += 1;
+                    old_left = old_left.saturating_sub(1);
+                    new_left = new_left.saturating_sub(1);
+                }
+            }
+        }
+        Self { files }
+    }
+
+    pub fn files(&self) -> Vec<PathBuf> {
+        let cwd = path_key(Path::new("."));
+        let mut out: Vec<PathBuf> = self
+            .files
+            .keys()
+            .filter(|p| p.is_file())
+            .map(|p| {
+                p.strip_prefix(&cwd)
+                    .map(Path::to_path_buf)
+                    .unwrap_or_else(|_| p.clone())
+            })
+            .collect();
+        out.sort();
+        out
+    }
+
+    pub fn contains_file(&self, path: &Path) -> bool {
+        self.files.contains_key(&path_key(path))
+    }
+
+    pub fn filter(&self, input_path: &Path, violations: Vec<Violation>) -> Vec<Violation> {
+        let input_key = path_key(input_path);
+        violations
+            .into_iter()
+            .filter(|v| {
+                if v.rule_id.starts_with("sys.") {
+                    return true;
+                }
+                let key = match v.location.file.as_deref() {
+                    Some(file) => path_key(Path::new(file)),
+                    None => input_key.clone(),
+                };
+                let first = v.location.line;
+                let last = v.location.end_line.max(first);
+                self.files
+                    .get(&key)
+                    .is_some_and(|ranges| ranges.iter().any(|(s, e)| *s <= last && first <= *e))
+            })
+            .collect()
+    }
+}
+
+fn git(args: &[&str]) -> Result<String, ConfigError> {
+    let output = Command::new("git")
+        .args(args)
+        .output()
+        .map_err(|e| ConfigError::IoFailed {
+            detail: format!("git {} ({})", args.join(" "), e),
+        })?;
+    if !output.status.success() {
+        return Err(ConfigError::InvalidValue {
+            detail: format!(
+                "git {} failed: {}",
+                args.join(" "),
+                String::from_utf8_lossy(&output.stderr).trim()
+            ),
+        });
+    }
+    String::from_utf8(output.stdout).map_err(|_| ConfigError::InvalidValue {
+        detail: format!("git {} produced invalid utf-8", args.join(" ")),
+    })
+}
+
+fn parse_hunk_header(line: &str) -> Option<(u32, u32, u32)> {
+    let mut parts = line.strip_prefix("@@ -")?.split_whitespace();
+    let (_, old_count) = parse_range(parts.next()?)?;
+    let (new_start, new_count) = parse_range(parts.next()?.strip_prefix('+')?)?;
+    Some((new_start, old_count, new_count))
+}
+
+fn parse_range(range: &str) -> Option<(u32, u32)> {
+    match range.split_once(',') {
+        Some((start, count)) => Some((start.parse().ok()?, count.parse().ok()?)),
+        None => Some((range.parse().ok()?, 1)),
+    }
+}
+
+fn path_key(path: &Path) -> PathBuf {
+    if let Ok(canonical) = fs::canonicalize(path) {
+        return canonical;
+    }
+    match std::env::current_dir() {
+        Ok(cwd) if path.is_relative() => cwd.join(path),
+        _ => path.to_path_buf(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Location, Severity};
+
+    const DIFF: &str = "diff --git a/rtl/top.sv b/rtl/top.sv
+--- a/rtl/top.sv
++++ b/rtl/top.sv
+@@ -2,3 +2,4 @@ module top;
+   wire a;
+-  wire b;
++  wire B;
++  wire C;
+   wire d;
+@@ -10,0 +12 @@
++  wire e;
+--- a/rtl/gone.sv
++++ /dev/null
+@@ -1 +0,0 @@
+-module gone; endmodule
+";
+
+    fn violation(line: u32, end_line: u32) -> Violation {
+        Violation {
+            rule_id: "r".to_string(),
+            severity: Severity::Warning,
+            message: String::new(),
+            location: Location {
+                line,
+                col: 1,
+                end_line,
+                end_col: 1,
+                file: None,
+            },
+            fixes: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn parses_added_line_ranges() {
+        let changes = ChangedLines::parse(DIFF, Path::new("/repo"));
+        let top = path_key(Path::new("/repo/rtl/top.sv"));
+        assert_eq!(changes.files.get(&top).unwrap(), &vec![(3, 4), (12, 12)]);
+        assert_eq!(changes.files.len(), 1);
+    }
+
+    #[test]
+    fn filters_violations_outside_changed_lines() {
+        let changes = ChangedLines::parse(DIFF, Path::new("/repo"));
+        let kept = changes.filter(
+            Path::new("/repo/rtl/top.sv"),
+            vec![violation(2, 2), violation(1, 3), violation(5, 5), violation(12, 12)],
+        );
+        let lines: Vec<_> = kept.iter().map(|v| v.location.line).collect();
+        assert_eq!(lines, vec![1, 12]);
+        assert!(changes
+            .filter(Path::new("/repo/other.sv"), vec![violation(1, 1)])
+            .is_empty());
+    }
+}
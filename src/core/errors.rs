@@ -56,6 +56,13 @@ pub enum PluginError {
     BadJson { detail: String },
     #[error("plugin protocol error: {detail}")]
     ProtocolError { detail: String },
+    #[error(
+        "plugin protocol mismatch: host speaks {}, sv-mint speaks v{core}",
+        .host.map_or("an unversioned protocol".to_string(), |v| format!("v{v}"))
+    )]
+    ProtocolMismatch { host: Option<u32>, core: u32 },
+    #[error("plugin host does not support {payload} schema {version}")]
+    UnsupportedSchema { payload: String, version: u32 },
     #[error("plugin exit nonzero: code={code}")]
     ExitCode { code: i32 },
     #[error("plugin stdout too large")]
@@ -0,0 +1,300 @@
+use crate::types::{TextEdit, Violation};
+
+pub const MAX_FIX_PASSES: usize = 10;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FixMode {
+    Apply,
+    DryRun,
+}
+
+pub fn select_edits(violations: &[Violation]) -> Vec<TextEdit> {
+    let mut accepted: Vec<TextEdit> = Vec::new();
+    for v in violations {
+        let Some(fix) = v.fixes.first() else { continue };
+        if fix.edits.is_empty() {
+            continue;
+        }
+        let self_overlap = fix
+            .edits
+            .iter()
+            .enumerate()
+            .any(|(i, a)| fix.edits[i + 1..].iter().any(|b| overlaps(a, b)));
+        if self_overlap {
+            continue;
+        }
+        if fix.edits.iter().any(|e| accepted.iter().any(|a| overlaps(a, e))) {
+            continue;
+        }
+        accepted.extend(fix.edits.iter().cloned());
+    }
+    accepted.sort_by_key(|e| (e.start, e.end));
+    accepted
+}
+
+fn overlaps(a: &TextEdit, b: &TextEdit) -> bool {
+    a.start == b.start || (a.start < b.end && b.start < a.end)
+}
+
+pub fn apply_edits(text: &str, edits: &[TextEdit]) -> Option<String> {
+    let mut out = String::with_capacity(text.len());
+    let mut cursor = 0usize;
+    for edit in edits {
+        if edit.start < cursor
+            || edit.start > edit.end
+            || edit.end > text.len()
+            || !text.is_char_boundary(edit.start)
+            || !text.is_char_boundary(edit.end)
+        {
+            return None;
+        }
+        out.push_str(&text[cursor..edit.start]);
+        out.push_str(&edit.replacement);
+        cursor = edit.end;
+    }
+    out.push_str(&text[cursor..]);
+    Some(out)
+}
+
+pub fn apply_edits_to_raw(raw: &str, edits: &[TextEdit]) -> Option<String> {
+    let bytes = raw.as_bytes();
+    let mut offsets = Vec::with_capacity(bytes.len() + 1);
+    let mut i = if raw.starts_with('\u{feff}') { 3 } else { 0 };
+    while i < bytes.len() {
+        offsets.push(i);
+        i += if bytes[i..].starts_with(b"\r\n") { 2 } else { 1 };
+    }
+    offsets.push(bytes.len());
+    let mapped = edits
+        .iter()
+        .map(|e| {
+            let start = *offsets.get(e.start)?;
+            Some(TextEdit {
+                start,
+                end: *offsets.get(e.end)?,
+                replacement: e.replacement.replace('\n', line_ending(raw, start)),
+            })
+        })
+        .collect::<Option<Vec<_>>>()?;
+    apply_edits(raw, &mapped)
+}
+
+fn line_ending(raw: &str, from: usize) -> &'static str {
+    let rest = &raw[from..];
+    match rest.find(['\r', '\n']) {
+        Some(at) if rest[at..].starts_with("\r\n") => "\r\n",
+        Some(at) if rest[at..].starts_with('\r') => "\r",
+        _ => "\n",
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Equal,
+    Delete,
+    Insert,
+}
+
+pub fn unified_diff(path: &str, old: &str, new: &str) -> String {
+    const CONTEXT: usize = 3;
+    let a: Vec<&str> = old.split_inclusive('\n').collect();
+    let b: Vec<&str> = new.split_inclusive('\n').collect();
+    let ops = diff_ops(&a, &b);
+    if ops.iter().all(|(op, _, _)| *op == Op::Equal) {
+        return String::new();
+    }
+    let mut out = format!("--- {}\n+++ {}\n", path, path);
+    let mut i = 0usize;
+    while i < ops.len() {
+        if ops[i].0 == Op::Equal {
+            i += 1;
+            continue;
+        }
+        let start = i.saturating_sub(CONTEXT);
+        let mut end = i;
+        loop {
+            while end < ops.len() && ops[end].0 != Op::Equal {
+                end += 1;
+            }
+            let mut run = end;
+            while run < ops.len() && ops[run].0 == Op::Equal {
+                run += 1;
+            }
+            if run < ops.len() && run - end <= CONTEXT * 2 {
+                end = run;
+                continue;
+            }
+            end = (end + CONTEXT).min(ops.len());
+            break;
+        }
+        let hunk = &ops[start..end];
+        let old_start = hunk.iter().map(|h| h.1).next().unwrap_or(0);
+        let new_start = hunk.iter().map(|h| h.2).next().unwrap_or(0);
+        let old_count = hunk.iter().filter(|h| h.0 != Op::Insert).count();
+        let new_count = hunk.iter().filter(|h| h.0 != Op::Delete).count();
+        out.push_str(&format!(
+            "@@ -{} +{} @@\n",
+            hunk_range(old_start, old_count),
+            hunk_range(new_start, new_count)
+        ));
+        for (op, ai, bi) in hunk {
+            let (prefix, line) = match op {
+                Op::Equal => (' ', a[*ai]),
+                Op::Delete => ('-', a[*ai]),
+                Op::Insert => ('+', b[*bi]),
+            };
+            out.push(prefix);
+            out.push_str(line);
+            if !line.ends_with('\n') {
+                out.push_str("\n\\ No newline at end of file\n");
+            }
+        }
+        i = end;
+    }
+    out
+}
+
+fn hunk_range(start: usize, count: usize) -> String {
+    if count == 0 {
+        format!("{},0", start)
+    } else if count == 1 {
+        format!("{}", start + 1)
+    } else {
+        format!("{},{}", start + 1, count)
+    }
+}
+
+fn diff_ops(a: &[&str], b: &[&str]) -> Vec<(Op, usize, usize)> {
+    let prefix = a.iter().zip(b.iter()).take_while(|(x, y)| x == y).count();
+    let suffix = a[prefix..]
+        .iter()
+        .rev()
+        .zip(b[prefix..].iter().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+    let am = &a[prefix..a.len() - suffix];
+    let bm = &b[prefix..b.len() - suffix];
+    let (n, m) = (am.len(), bm.len());
+    let mut lcs = vec![vec![0u32; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if am[i] == bm[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+    let mut ops: Vec<(Op, usize, usize)> = (0..prefix).map(|k| (Op::Equal, k, k)).collect();
+    let (mut i, mut j) = (0usize, 0usize);
+    while i < n || j < m {
+        if i < n && j < m && am[i] == bm[j] {
+            ops.push((Op::Equal, prefix + i, prefix + j));
+            i += 1;
+            j += 1;
+        } else if i < n && (j == m || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            ops.push((Op::Delete, prefix + i, prefix + j));
+            i += 1;
+        } else {
+            ops.push((Op::Insert, prefix + i, prefix + j));
+            j += 1;
+        }
+    }
+    for k in 0..suffix {
+        ops.push((Op::Equal, prefix + n + k, prefix + m + k));
+    }
+    ops
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Fix, Location, Severity};
+
+    fn fixable(edits: &[(usize, usize, &str)]) -> Violation {
+        Violation {
+            rule_id: "r".to_string(),
+            severity: Severity::Warning,
+            message: String::new(),
+            location: Location {
+                line: 1,
+                col: 1,
+                end_line: 1,
+                end_col: 1,
+                file: None,
+            },
+            fixes: vec![Fix {
+                description: None,
+                edits: edits
+                    .iter()
+                    .map(|(start, end, replacement)| TextEdit {
+                        start: *start,
+                        end: *end,
+                        replacement: replacement.to_string(),
+                    })
+                    .collect(),
+            }],
+        }
+    }
+
+    #[test]
+    fn overlapping_fixes_are_deferred() {
+        let text = "a or b or c";
+        let vs = vec![
+            fixable(&[(1, 4, ",")]),
+            fixable(&[(3, 6, "!")]),
+            fixable(&[(6, 9, ",")]),
+        ];
+        let edits = select_edits(&vs);
+        assert_eq!(edits.len(), 2);
+        assert_eq!(apply_edits(text, &edits).unwrap(), "a, b, c");
+    }
+
+    #[test]
+    fn rejects_out_of_range_edits() {
+        let edits = vec![TextEdit {
+            start: 2,
+            end: 10,
+            replacement: String::new(),
+        }];
+        assert!(apply_edits("abc", &edits).is_none());
+    }
+
+    #[test]
+    fn unified_diff_emits_hunk_with_context() {
+        let old = "1\n2\n3\n4\n5\n6\n7\n8\n";
+        let new = "1\n2\n3\n4\nfive\n6\n7\n8\n";
+        let diff = unified_diff("x.sv", old, new);
+        assert_eq!(
+            diff,
+            "--- x.sv\n+++ x.sv\n@@ -2,7 +2,7 @@\n 2\n 3\n 4\n-5\n+five\n 6\n 7\n 8\n"
+        );
+        assert!(unified_diff("x.sv", old, old).is_empty());
+    }
+
+    #[test]
+    fn raw_edits_keep_each_line_ending_and_bom() {
+        let raw = "\u{feff}a or b\r\nc or d\ne or f\r\n";
+        let edits = vec![
+            TextEdit {
+                start: 1,
+                end: 4,
+                replacement: ",".to_string(),
+            },
+            TextEdit {
+                start: 8,
+                end: 11,
+                replacement: ",\n".to_string(),
+            },
+            TextEdit {
+                start: 15,
+                end: 18,
+                replacement: ",".to_string(),
+            },
+        ];
+        assert_eq!(
+            apply_edits_to_raw(raw, &edits).unwrap(),
+            "\u{feff}a, b\r\nc,\n d\ne, f\r\n"
+        );
+    }
+}
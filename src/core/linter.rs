@@ -0,0 +1,45 @@
+pub use crate::core::pipeline::{FileReport, ParseStatus};
+
+use crate::config::{read_input, Config, InputText};
+use crate::core::pipeline::Pipeline;
+use crate::plugin::HostPool;
+use anyhow::Result;
+use std::path::Path;
+
+pub struct Linter {
+    cfg: Config,
+    host: Option<HostPool>,
+    host_started: bool,
+}
+
+impl Linter {
+    pub fn new(cfg: Config) -> Self {
+        Self {
+            cfg,
+            host: None,
+            host_started: false,
+        }
+    }
+
+    pub fn config(&self) -> &Config {
+        &self.cfg
+    }
+
+    pub fn lint_file(&mut self, path: impl AsRef<Path>) -> Result<FileReport> {
+        let (text, path) = read_input(path.as_ref())?;
+        self.lint(&path, &text)
+    }
+
+    pub fn lint_source(&mut self, name: impl AsRef<Path>, text: &str) -> Result<FileReport> {
+        self.lint(name.as_ref(), &InputText::new(text.to_string()))
+    }
+
+    fn lint(&mut self, path: &Path, text: &InputText) -> Result<FileReport> {
+        let pipeline = Pipeline::new(&self.cfg);
+        if !self.host_started {
+            self.host = pipeline.start_host()?;
+            self.host_started = true;
+        }
+        pipeline.lint_buffer(path, text, self.host.as_mut())
+    }
+}
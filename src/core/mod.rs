@@ -1,6 +1,15 @@
+pub mod baseline;
+pub mod cache;
+pub mod changes;
 pub mod errors;
+pub mod fix;
 pub mod linemap;
+pub mod linter;
+pub mod native;
 pub mod payload;
 pub mod pipeline;
+pub mod profile;
 pub mod size_guard;
+pub mod suppress;
 pub mod types;
+pub mod watch;
@@ -0,0 +1,56 @@
+mod naming;
+
+use crate::sv::model::ParseArtifacts;
+use crate::types::{Stage, Violation};
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::OnceLock;
+
+pub struct StageContext<'a> {
+    pub stage: Stage,
+    pub path: &'a Path,
+    pub artifacts: &'a ParseArtifacts,
+    pub options: &'a Map<String, Value>,
+}
+
+pub trait Rule: Send + Sync {
+    fn id(&self) -> &'static str;
+    fn stage(&self) -> Stage;
+    fn description(&self) -> &'static str;
+    fn rationale(&self) -> Option<&'static str> {
+        None
+    }
+    fn tags(&self) -> &'static [&'static str] {
+        &[]
+    }
+    fn check(&self, ctx: &StageContext<'_>) -> Vec<Violation>;
+}
+
+#[derive(Default)]
+pub struct Registry {
+    rules: BTreeMap<&'static str, Box<dyn Rule>>,
+}
+
+impl Registry {
+    pub fn register(&mut self, rule: Box<dyn Rule>) {
+        self.rules.insert(rule.id(), rule);
+    }
+
+    pub fn get(&self, id: &str) -> Option<&dyn Rule> {
+        self.rules.get(id).map(|r| r.as_ref())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &dyn Rule> {
+        self.rules.values().map(|r| r.as_ref())
+    }
+}
+
+pub fn builtin() -> &'static Registry {
+    static REGISTRY: OnceLock<Registry> = OnceLock::new();
+    REGISTRY.get_or_init(|| {
+        let mut registry = Registry::default();
+        naming::register(&mut registry);
+        registry
+    })
+}
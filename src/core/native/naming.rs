@@ -0,0 +1,208 @@
+use super::{Registry, Rule, StageContext};
+use crate::sv::model::DeclKind;
+use crate::types::{Location, Severity, Stage, Violation};
+
+pub(super) fn register(registry: &mut Registry) {
+    registry.register(Box::new(ModuleNamesLowerSnake));
+    registry.register(Box::new(PortNamesLowerSnake));
+    registry.register(Box::new(ParameterNamesUppercase));
+    registry.register(Box::new(LocalparamNamesUppercase));
+}
+
+struct ModuleNamesLowerSnake;
+
+impl Rule for ModuleNamesLowerSnake {
+    fn id(&self) -> &'static str {
+        "module_names_lower_snake"
+    }
+
+    fn stage(&self) -> Stage {
+        Stage::Ast
+    }
+
+    fn description(&self) -> &'static str {
+        "module names use lower_snake_case"
+    }
+
+    fn rationale(&self) -> Option<&'static str> {
+        Some("A single naming style for modules keeps hierarchies readable and file names predictable.")
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["naming"]
+    }
+
+    fn check(&self, ctx: &StageContext<'_>) -> Vec<Violation> {
+        ctx.artifacts
+            .ast
+            .decls
+            .iter()
+            .filter(|d| matches!(d.kind, DeclKind::Module) && !is_lower_snake(&d.name))
+            .map(|d| warning(self.id(), format!("{} must use lower_snake_case", d.name), &d.loc))
+            .collect()
+    }
+}
+
+struct PortNamesLowerSnake;
+
+impl Rule for PortNamesLowerSnake {
+    fn id(&self) -> &'static str {
+        "port_names_lower_snake"
+    }
+
+    fn stage(&self) -> Stage {
+        Stage::Ast
+    }
+
+    fn description(&self) -> &'static str {
+        "port names use lower_snake_case"
+    }
+
+    fn rationale(&self) -> Option<&'static str> {
+        Some("A single naming style for ports keeps instantiations readable.")
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["naming"]
+    }
+
+    fn check(&self, ctx: &StageContext<'_>) -> Vec<Violation> {
+        ctx.artifacts
+            .ast
+            .ports
+            .iter()
+            .filter(|p| !is_lower_snake(&p.name))
+            .map(|p| warning(self.id(), format!("{} must use lower_snake_case", p.name), &p.loc))
+            .collect()
+    }
+}
+
+struct ParameterNamesUppercase;
+
+impl Rule for ParameterNamesUppercase {
+    fn id(&self) -> &'static str {
+        "parameter_names_uppercase"
+    }
+
+    fn stage(&self) -> Stage {
+        Stage::Ast
+    }
+
+    fn description(&self) -> &'static str {
+        "parameter names use UpperCamelCase"
+    }
+
+    fn rationale(&self) -> Option<&'static str> {
+        Some("Capitalised parameters are easy to tell apart from signals when reading expressions.")
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["naming"]
+    }
+
+    fn check(&self, ctx: &StageContext<'_>) -> Vec<Violation> {
+        ctx.artifacts
+            .ast
+            .decls
+            .iter()
+            .filter(|d| matches!(d.kind, DeclKind::Param) && !d.name.is_empty() && !is_upper_camel(&d.name))
+            .map(|d| {
+                warning(
+                    self.id(),
+                    format!("parameter {} should use UpperCamelCase", d.name),
+                    &d.loc,
+                )
+            })
+            .collect()
+    }
+}
+
+struct LocalparamNamesUppercase;
+
+impl Rule for LocalparamNamesUppercase {
+    fn id(&self) -> &'static str {
+        "localparam_names_uppercase"
+    }
+
+    fn stage(&self) -> Stage {
+        Stage::Ast
+    }
+
+    fn description(&self) -> &'static str {
+        "localparam names use UpperCamelCase or ALL_CAPS"
+    }
+
+    fn rationale(&self) -> Option<&'static str> {
+        Some("Capitalised constants are easy to tell apart from signals when reading expressions.")
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["naming"]
+    }
+
+    fn check(&self, ctx: &StageContext<'_>) -> Vec<Violation> {
+        ctx.artifacts
+            .ast
+            .decls
+            .iter()
+            .filter(|d| matches!(d.kind, DeclKind::LocalParam))
+            .filter(|d| !is_ascii_upper_camel(&d.name) && !is_all_caps(&d.name))
+            .map(|d| {
+                warning(
+                    self.id(),
+                    format!("localparam {} should use UpperCamelCase or ALL_CAPS", d.name),
+                    &d.loc,
+                )
+            })
+            .collect()
+    }
+}
+
+fn warning(rule_id: &str, message: String, loc: &Location) -> Violation {
+    Violation {
+        rule_id: rule_id.to_string(),
+        severity: Severity::Warning,
+        message,
+        location: loc.clone(),
+        fixes: Vec::new(),
+    }
+}
+
+fn is_lower_snake(name: &str) -> bool {
+    name.chars().next().is_some_and(char::is_lowercase) && name.chars().all(|c| c.is_alphanumeric() || c == '_')
+}
+
+fn is_upper_camel(name: &str) -> bool {
+    let all_upper = name.chars().any(char::is_uppercase) && !name.chars().any(char::is_lowercase);
+    !all_upper && name.chars().next().is_some_and(char::is_uppercase) && name.chars().all(char::is_alphanumeric)
+}
+
+fn is_ascii_upper_camel(name: &str) -> bool {
+    name.chars().next().is_some_and(|c| c.is_ascii_uppercase()) && name.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+fn is_all_caps(name: &str) -> bool {
+    name.chars().next().is_some_and(|c| c.is_ascii_uppercase())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn naming_predicates_match_python_rules() {
+        assert!(is_lower_snake("my_module2"));
+        assert!(!is_lower_snake("MyModule"));
+        assert!(!is_lower_snake("_hidden"));
+        assert!(is_upper_camel("DataWidth"));
+        assert!(!is_upper_camel("DATA"));
+        assert!(!is_upper_camel("Data_Width"));
+        assert!(!is_upper_camel("dataWidth"));
+        assert!(is_ascii_upper_camel("Depth"));
+        assert!(is_all_caps("MAX_DEPTH"));
+        assert!(!is_all_caps("Max_depth"));
+    }
+}
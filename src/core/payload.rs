@@ -3,6 +3,7 @@ use crate::sv::model::{AstSummary, ParseArtifacts};
 use crate::types::Stage;
 use serde::Serialize;
 
+#[derive(Clone, Copy)]
 pub enum StagePayload<'a> {
     RawText(&'a str),
     PpText {
@@ -1,29 +1,51 @@
-use crate::config::{read_input, Config, TransportOnExceed};
+use crate::config::{read_input, Config, InputText, RuleConfig, TransportOnExceed};
+use crate::core::baseline::{anchor_dir, BaselineMode};
+use crate::core::cache::ResultCache;
+use crate::core::changes::ChangedLines;
+use crate::core::fix::{apply_edits, apply_edits_to_raw, select_edits, unified_diff, FixMode, MAX_FIX_PASSES};
+use crate::core::linemap::{LineMap, SpanBytes};
+use crate::core::native::{self, StageContext};
 use crate::core::payload::{payload_for, StagePayload};
+use crate::core::profile::{Profile, RuleTiming};
 use crate::core::size_guard::{
     enforce_request_size, enforce_response_size, OnExceed, SizePolicy, StageOutcome, StageStatus,
 };
+use crate::core::suppress::Suppressions;
 use crate::diag::event::{Ev, Event};
 use crate::diag::logging::log_event;
-use crate::output::print_violations;
-use crate::plugin::client::{PythonHost, RuleDispatch};
+use crate::output::{reporter_for, Reporter};
+use crate::plugin::client::{parse_severity, RuleDispatch};
+use crate::plugin::{HostPool, RestartBudget};
+use crate::sv::model::ParseArtifacts;
 use crate::svparser::SvDriver;
-use crate::types::{Location, Severity, Stage, Violation};
+use crate::types::{Fix, Location, Severity, Stage, Violation};
 use anyhow::{anyhow, Result};
 use serde::Serialize;
-use std::collections::HashMap;
+use serde_json::{Map, Value};
+use std::collections::{HashMap, HashSet};
+use std::fs;
 use std::path::{Path, PathBuf};
 use std::process::ExitCode;
 use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
 use std::time::Instant;
-use tracing::{debug, error};
+use tracing::{debug, error, warn};
 
+const PARSE_FAILED: &str = "sys.parse.failed";
+const NATIVE_UNKNOWN: &str = "sys.native.unknown";
+
+#[derive(Default)]
 pub struct RunSummary {
     pub violations: usize,
     pub had_error: bool,
 }
 
 impl RunSummary {
+    pub fn merge(&mut self, other: RunSummary) {
+        self.violations += other.violations;
+        self.had_error |= other.had_error;
+    }
+
     pub fn exit_code(&self) -> ExitCode {
         if self.had_error {
             ExitCode::from(3)
@@ -37,14 +59,131 @@ impl RunSummary {
 
 pub struct Pipeline<'a> {
     pub cfg: &'a Config,
+    reporter: Arc<Mutex<Box<dyn Reporter>>>,
+    baseline: Option<Arc<Mutex<BaselineMode>>>,
+    changed: Option<Arc<ChangedLines>>,
+    fix_mode: Option<FixMode>,
+    restarts: Arc<RestartBudget>,
+    profile: Option<Arc<Mutex<Profile>>>,
+    cache: Option<Arc<ResultCache>>,
+    sources: Arc<HashMap<PathBuf, InputText>>,
+}
+
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+#[serde(tag = "status", rename_all = "lowercase")]
+pub enum ParseStatus {
+    Parsed,
+    Failed { message: String },
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct FileReport {
+    pub path: PathBuf,
+    pub violations: Vec<Violation>,
+    pub stage_outcomes: Vec<StageOutcome>,
+    pub parse_status: ParseStatus,
+}
+
+#[derive(Clone)]
+struct FileLint {
+    violations: Vec<Violation>,
+    aborted: Option<String>,
+    outcomes: Vec<StageOutcome>,
+    parse_status: ParseStatus,
 }
 
 impl<'a> Pipeline<'a> {
     pub fn new(cfg: &'a Config) -> Self {
-        Self { cfg }
+        Self::with_reporter(cfg, reporter_for(cfg))
+    }
+
+    pub fn with_reporter(cfg: &'a Config, reporter: Box<dyn Reporter>) -> Self {
+        Self {
+            cfg,
+            reporter: Arc::new(Mutex::new(reporter)),
+            baseline: None,
+            changed: None,
+            fix_mode: None,
+            restarts: Arc::new(RestartBudget::new(cfg.plugin.max_restarts)),
+            profile: None,
+            cache: None,
+            sources: Arc::new(HashMap::new()),
+        }
+    }
+
+    pub fn with_fix_mode(mut self, mode: FixMode) -> Self {
+        self.fix_mode = Some(mode);
+        self
+    }
+
+    pub fn with_baseline(mut self, mode: BaselineMode) -> Self {
+        self.baseline = Some(Arc::new(Mutex::new(mode)));
+        self
+    }
+
+    pub fn with_changed_lines(mut self, changed: ChangedLines) -> Self {
+        self.changed = Some(Arc::new(changed));
+        self
+    }
+
+    pub fn with_profile(mut self) -> Self {
+        self.profile = Some(Arc::new(Mutex::new(Profile::default())));
+        self
+    }
+
+    pub fn with_cache(mut self, cache: ResultCache) -> Self {
+        self.cache = Some(Arc::new(cache));
+        self
+    }
+
+    pub fn with_source(mut self, path: PathBuf, text: InputText) -> Self {
+        Arc::make_mut(&mut self.sources).insert(path, text);
+        self
+    }
+
+    pub fn profile(&self) -> Option<Arc<Mutex<Profile>>> {
+        self.profile.clone()
+    }
+
+    pub fn for_config<'b>(&self, cfg: &'b Config) -> Pipeline<'b> {
+        Pipeline {
+            cfg,
+            reporter: self.reporter.clone(),
+            baseline: self.baseline.clone(),
+            changed: self.changed.clone(),
+            fix_mode: self.fix_mode,
+            restarts: self.restarts.clone(),
+            profile: self.profile.clone(),
+            cache: self.cache.clone(),
+            sources: self.sources.clone(),
+        }
     }
 
     pub fn run_files(&self, inputs: &[PathBuf]) -> Result<RunSummary> {
+        let summary = self.run_inputs(inputs)?;
+        self.finish(&summary)?;
+        Ok(summary)
+    }
+
+    pub fn finish(&self, summary: &RunSummary) -> Result<()> {
+        if let Some(baseline) = &self.baseline {
+            if let Ok(mode) = baseline.lock() {
+                mode.finish()?;
+            }
+        }
+        if let Ok(mut reporter) = self.reporter.lock() {
+            reporter.finish(summary)?;
+        }
+        if let Some(cache) = &self.cache {
+            let removed = cache.prune();
+            if removed > 0 {
+                debug!("pruned {} entries from {}", removed, cache.dir().display());
+            }
+        }
+        Ok(())
+    }
+
+    pub fn run_inputs(&self, inputs: &[PathBuf]) -> Result<RunSummary> {
         if inputs.is_empty() {
             return Ok(RunSummary {
                 violations: 0,
@@ -64,8 +203,17 @@ impl<'a> Pipeline<'a> {
         if !self.has_enabled_rules() {
             return self.run_file_without_rules(input);
         }
-        let mut host = PythonHost::start(self.cfg).map_err(anyhow::Error::new)?;
-        self.run_file_with_host(input, &mut host)
+        let mut host = self.start_host()?;
+        self.run_file_with_host(input, host.as_mut())
+    }
+
+    pub fn start_host(&self) -> Result<Option<HostPool>> {
+        if !self.needs_plugin_host() {
+            return Ok(None);
+        }
+        HostPool::start(self.cfg)
+            .map(|pool| Some(pool.with_restart_budget(self.restarts.clone())))
+            .map_err(anyhow::Error::new)
     }
 
     fn run_files_parallel(&self, inputs: &[PathBuf]) -> Result<RunSummary> {
@@ -79,7 +227,7 @@ impl<'a> Pipeline<'a> {
         std::thread::scope(|scope| {
             let mut handles = Vec::new();
             for _ in 0..worker_count {
-                let pipeline = Pipeline { cfg: self.cfg };
+                let pipeline = self.for_config(self.cfg);
                 let counter = &index;
                 handles.push(scope.spawn(move || pipeline.run_worker(inputs, total, counter)));
             }
@@ -93,10 +241,7 @@ impl<'a> Pipeline<'a> {
         };
         for res in results {
             match res {
-                Ok(r) => {
-                    summary.violations += r.violations;
-                    summary.had_error |= r.had_error;
-                }
+                Ok(r) => summary.merge(r),
                 Err(e) => return Err(e),
             }
         }
@@ -104,13 +249,17 @@ impl<'a> Pipeline<'a> {
     }
 
     fn run_file_batch(&self, inputs: &[PathBuf]) -> Result<RunSummary> {
-        let mut host = PythonHost::start(self.cfg).map_err(anyhow::Error::new)?;
+        let mut host = self.start_host()?;
+        Ok(self.run_batch_with_host(inputs, host.as_mut()))
+    }
+
+    pub fn run_batch_with_host(&self, inputs: &[PathBuf], mut host: Option<&mut HostPool>) -> RunSummary {
         let mut summary = RunSummary {
             violations: 0,
             had_error: false,
         };
         for path in inputs {
-            match self.run_file_with_host(path, &mut host) {
+            match self.run_file_with_host(path, host.as_deref_mut()) {
                 Ok(n) => summary.violations += n,
                 Err(e) => {
                     summary.had_error = true;
@@ -118,11 +267,11 @@ impl<'a> Pipeline<'a> {
                 }
             }
         }
-        Ok(summary)
+        summary
     }
 
     fn run_worker(&self, inputs: &[PathBuf], total: usize, counter: &AtomicUsize) -> Result<RunSummary> {
-        let mut host = PythonHost::start(self.cfg).map_err(anyhow::Error::new)?;
+        let mut host = self.start_host()?;
         let mut summary = RunSummary {
             violations: 0,
             had_error: false,
@@ -133,7 +282,7 @@ impl<'a> Pipeline<'a> {
                 break;
             }
             let path = &inputs[next];
-            match self.run_file_with_host(path, &mut host) {
+            match self.run_file_with_host(path, host.as_mut()) {
                 Ok(n) => summary.violations += n,
                 Err(e) => {
                     summary.had_error = true;
@@ -144,14 +293,148 @@ impl<'a> Pipeline<'a> {
         Ok(summary)
     }
 
-    fn run_file_with_host(&self, input: &Path, host: &mut PythonHost) -> Result<usize> {
-        let (input_text, input_path) = read_input(input)?;
+    fn run_file_with_host(&self, input: &Path, host: Option<&mut HostPool>) -> Result<usize> {
+        let (input_text, input_path) = self.read(input)?;
+        if let Some(mode) = self.fix_mode {
+            return self.run_fix_loop(&input_path, input_text, host, mode);
+        }
+        let lint = self.lint_source(&input_path, &input_text, host)?;
+        self.finish_file(&input_path, &input_text.normalized, lint)
+    }
+
+    pub fn lint_buffer(&self, path: &Path, text: &InputText, host: Option<&mut HostPool>) -> Result<FileReport> {
+        let lint = self.lint_source(path, text, host)?;
+        Ok(FileReport {
+            path: path.to_path_buf(),
+            violations: lint.violations,
+            stage_outcomes: lint.outcomes,
+            parse_status: lint.parse_status,
+        })
+    }
+
+    fn run_fix_loop(
+        &self,
+        input_path: &Path,
+        original: InputText,
+        mut host: Option<&mut HostPool>,
+        mode: FixMode,
+    ) -> Result<usize> {
+        let mut current = original.normalized.clone();
+        let mut raw = original.raw.clone();
+        let first = self.lint_source(input_path, &original, host.as_deref_mut())?;
+        let mut lint = first.clone();
+        for _ in 0..MAX_FIX_PASSES {
+            if lint.aborted.is_some() {
+                break;
+            }
+            let edits = select_edits(&lint.violations);
+            if edits.is_empty() {
+                break;
+            }
+            let (Some(next), Some(next_raw)) = (apply_edits(&current, &edits), apply_edits_to_raw(&raw, &edits)) else {
+                break;
+            };
+            if next == current {
+                break;
+            }
+            current = next;
+            raw = next_raw;
+            let text = InputText {
+                raw: current.clone(),
+                normalized: current.clone(),
+            };
+            lint = self.lint_source(input_path, &text, host.as_deref_mut())?;
+        }
+        if current != original.normalized {
+            match mode {
+                FixMode::Apply => {
+                    fs::write(input_path, &raw).map_err(|e| anyhow!("{}: {}", input_path.display(), e))?;
+                }
+                FixMode::DryRun => {
+                    let display = input_path.to_string_lossy().replace('\\', "/");
+                    print!("{}", unified_diff(&display, &original.normalized, &current));
+                    return self.finish_file(input_path, &original.normalized, first);
+                }
+            }
+        }
+        self.finish_file(input_path, &current, lint)
+    }
+
+    fn finish_file(&self, input_path: &Path, text: &str, lint: FileLint) -> Result<usize> {
+        let count = self.report(input_path, text, lint.violations)?;
+        if let Some(stage) = lint.aborted {
+            return Err(anyhow!(format!("stage {} aborted", stage)));
+        }
+        Ok(count)
+    }
+
+    fn lint_source(
+        &self,
+        input_path: &Path,
+        input_text: &InputText,
+        mut host: Option<&mut HostPool>,
+    ) -> Result<FileLint> {
+        let cached = self.cache.as_ref().map(|cache| {
+            let key = cache.key(self.cfg, &self.cfg.rules_for(input_path), input_path, &input_text.raw);
+            (cache, key)
+        });
+        if let Some((cache, key)) = &cached {
+            if let Some(violations) = cache.load(key) {
+                debug!(
+                    "{}: replaying {} cached violations",
+                    input_path.display(),
+                    violations.len()
+                );
+                let parse_status = match violations.iter().find(|v| v.rule_id == PARSE_FAILED) {
+                    Some(v) => ParseStatus::Failed {
+                        message: v.message.clone(),
+                    },
+                    None => ParseStatus::Parsed,
+                };
+                return Ok(FileLint {
+                    violations,
+                    aborted: None,
+                    outcomes: Vec::new(),
+                    parse_status,
+                });
+            }
+        }
+        let mut profile = Profile::default();
+        let mut lint = self.lint_source_profiled(input_path, input_text, host.as_deref_mut(), &mut profile);
+        if lint.is_err() {
+            if let Some(pool) = host {
+                if pool.restart_crashed(input_path)? {
+                    lint = self.lint_source_profiled(input_path, input_text, Some(pool), &mut profile);
+                }
+            }
+        }
+        if let Some(shared) = &self.profile {
+            if let Ok(mut shared) = shared.lock() {
+                shared.merge(profile);
+            }
+        }
+        if let (Ok(lint), Some((cache, key))) = (&lint, &cached) {
+            let transient = lint.violations.iter().any(|v| v.rule_id.starts_with("sys.plugin."));
+            if lint.aborted.is_none() && !transient {
+                cache.store(key, &lint.violations);
+            }
+        }
+        lint
+    }
+
+    fn lint_source_profiled(
+        &self,
+        input_path: &Path,
+        input_text: &InputText,
+        mut host: Option<&mut HostPool>,
+        profile: &mut Profile,
+    ) -> Result<FileLint> {
         let driver = SvDriver::new(&self.cfg.svparser);
-        let artifacts = match driver.parse_text(&input_text.raw, &input_text.normalized, &input_path) {
+        let artifacts = match driver.parse_text(&input_text.raw, &input_text.normalized, input_path) {
             Ok(a) => a,
             Err(e) => {
                 let violation = Violation {
-                    rule_id: "sys.parse.failed".to_string(),
+                    rule_id: PARSE_FAILED.to_string(),
                     severity: Severity::Error,
                     message: e.to_string(),
                     location: Location {
@@ -161,19 +444,38 @@ impl<'a> Pipeline<'a> {
                         end_col: 1,
                         file: Some(input_path.to_string_lossy().into_owned()),
                     },
+                    fixes: Vec::new(),
                 };
-                print_violations(&[violation], &input_path);
-                return Ok(1);
+                return Ok(FileLint {
+                    parse_status: ParseStatus::Failed {
+                        message: violation.message.clone(),
+                    },
+                    violations: vec![violation],
+                    aborted: None,
+                    outcomes: Vec::new(),
+                });
             }
         };
+        profile.record_phase("parse.preprocess", artifacts.timings.preprocess);
+        profile.record_phase("parse.parse", artifacts.timings.parse);
         let mut all: Vec<Violation> = Vec::new();
-        let stage_rule_map = build_stage_rule_map(self.cfg);
+        let mut outcomes: Vec<StageOutcome> = Vec::new();
+        let rules = self.cfg.rules_for(input_path);
+        let stage_rule_map = build_stage_rule_map(&rules);
         let input_display = input_path.to_string_lossy().into_owned();
+        let mut suppressions = Suppressions::scan(&artifacts.normalized_text);
+        let pp_is_source = artifacts.pp_text == artifacts.normalized_text;
+        let pp_lines = (!pp_is_source).then(|| {
+            (
+                LineMap::new(&artifacts.pp_text),
+                LineMap::new(&artifacts.normalized_text),
+            )
+        });
 
         for stage in &self.cfg.stages.enabled {
             log_event(Ev::new(Event::StageStart, &input_display).with_stage(stage.as_str()));
             let rules_for_stage = stage_rule_map.get(stage).expect("stage rule map missing entry");
-            if rules_for_stage.enabled.is_empty() {
+            if rules_for_stage.enabled.is_empty() && rules_for_stage.native.is_empty() {
                 let outcome = StageOutcome {
                     stage: stage.as_str().to_string(),
                     status: StageStatus::Skipped,
@@ -181,7 +483,8 @@ impl<'a> Pipeline<'a> {
                     duration_ms: 0,
                     fail_ci: false,
                 };
-                record_outcome(&input_path, &outcome);
+                record_outcome(input_path, &outcome);
+                outcomes.push(outcome);
                 log_event(
                     Ev::new(Event::StageDone, &input_display)
                         .with_stage(stage.as_str())
@@ -189,66 +492,180 @@ impl<'a> Pipeline<'a> {
                 );
                 continue;
             }
-            let payload = payload_for(stage, &artifacts);
-            let request_rules = RuleDispatch {
-                enabled: &rules_for_stage.enabled,
-                disabled: &rules_for_stage.disabled,
-            };
-            let invocation = StageRequest {
-                kind: "run_stage",
-                stage: stage.as_str(),
-                path: &input_path,
-                payload: &payload,
-                rules: request_rules,
-            };
-            let policy = self.size_policy(stage);
-            if let Err(outcome) = enforce_request_size(stage.as_str(), &invocation, &policy) {
-                all.extend(outcome.violations.iter().cloned());
-                record_outcome(&input_path, &outcome);
-                log_event(Ev::new(Event::StageDone, &input_display).with_stage(stage.as_str()));
-                if matches!(outcome.status, StageStatus::Failed) || outcome.fail_ci {
-                    print_violations(&all, &input_path);
-                    return Err(anyhow!(format!("stage {} aborted", stage.as_str())));
+            let t0 = Instant::now();
+            let (mut stage_violations, native_timings) =
+                run_native_rules(stage, input_path, &artifacts, rules_for_stage);
+            for timing in &native_timings {
+                profile.record_rule(stage.as_str(), timing);
+            }
+            if !rules_for_stage.enabled.is_empty() {
+                let host = host
+                    .as_deref_mut()
+                    .ok_or_else(|| anyhow!("stage {} has plugin rules but no plugin host", stage.as_str()))?;
+                let payload = payload_for(stage, &artifacts);
+                let request_rules = RuleDispatch {
+                    enabled: &rules_for_stage.enabled,
+                    disabled: &rules_for_stage.disabled,
+                    options: &rules_for_stage.options,
+                };
+                let invocation = StageRequest {
+                    kind: "run_stage",
+                    stage: stage.as_str(),
+                    path: input_path,
+                    payload: &payload,
+                    rules: request_rules,
+                };
+                let policy = self.size_policy(stage);
+                let t_ser = Instant::now();
+                let request_size = enforce_request_size(stage.as_str(), &invocation, &policy);
+                profile.record_phase("serialize", t_ser.elapsed());
+                if let Err(outcome) = request_size {
+                    all.extend(stage_violations);
+                    all.extend(outcome.violations.iter().cloned());
+                    record_outcome(input_path, &outcome);
+                    log_event(Ev::new(Event::StageDone, &input_display).with_stage(stage.as_str()));
+                    let abort = matches!(outcome.status, StageStatus::Failed) || outcome.fail_ci;
+                    outcomes.push(outcome);
+                    if abort {
+                        return Ok(FileLint {
+                            violations: suppressions.filter(all, &input_display),
+                            aborted: Some(stage.as_str().to_string()),
+                            outcomes,
+                            parse_status: ParseStatus::Parsed,
+                        });
+                    }
+                    continue;
                 }
-                continue;
+                let run_rules = RuleDispatch {
+                    enabled: &rules_for_stage.enabled,
+                    disabled: &rules_for_stage.disabled,
+                    options: &rules_for_stage.options,
+                };
+                let result = host
+                    .run_stage(stage, input_path, payload, run_rules)
+                    .map_err(anyhow::Error::new)?;
+                profile.record_phase("serialize", result.serialize);
+                for timing in &result.timings {
+                    profile.record_rule(stage.as_str(), timing);
+                }
+                if let Err(mut outcome) = enforce_response_size(stage.as_str(), result.response_bytes, &policy) {
+                    all.extend(stage_violations);
+                    all.extend(outcome.violations.iter().cloned());
+                    outcome.duration_ms = t0.elapsed().as_millis() as u64;
+                    record_outcome(input_path, &outcome);
+                    log_event(Ev::new(Event::StageDone, &input_display).with_stage(stage.as_str()));
+                    let abort = matches!(outcome.status, StageStatus::Failed) || outcome.fail_ci;
+                    outcomes.push(outcome);
+                    if abort {
+                        return Ok(FileLint {
+                            violations: suppressions.filter(all, &input_display),
+                            aborted: Some(stage.as_str().to_string()),
+                            outcomes,
+                            parse_status: ParseStatus::Parsed,
+                        });
+                    }
+                    continue;
+                }
+                stage_violations.extend(result.violations);
             }
-            let t0 = Instant::now();
-            let run_rules = RuleDispatch {
-                enabled: &rules_for_stage.enabled,
-                disabled: &rules_for_stage.disabled,
-            };
-            let result = host
-                .run_stage(stage, &input_path, payload, run_rules)
-                .map_err(anyhow::Error::new)?;
-            if let Err(mut outcome) = enforce_response_size(stage.as_str(), result.response_bytes, &policy) {
-                all.extend(outcome.violations.iter().cloned());
-                outcome.duration_ms = t0.elapsed().as_millis() as u64;
-                record_outcome(&input_path, &outcome);
-                log_event(Ev::new(Event::StageDone, &input_display).with_stage(stage.as_str()));
-                if matches!(outcome.status, StageStatus::Failed) || outcome.fail_ci {
-                    print_violations(&all, &input_path);
-                    return Err(anyhow!(format!("stage {} aborted", stage.as_str())));
+            for v in &mut stage_violations {
+                if let Some(sev) = rules_for_stage.severity.get(&v.rule_id) {
+                    v.severity = *sev;
+                }
+                if *stage != Stage::RawText && !pp_is_source {
+                    let before = v.fixes.len();
+                    v.fixes.retain_mut(|fix| map_fix_to_source(&artifacts, fix));
+                    if v.fixes.len() < before {
+                        warn!(
+                            "{}: dropped {} fix(es) for {} that touch preprocessor-generated text",
+                            input_display,
+                            before - v.fixes.len(),
+                            v.rule_id
+                        );
+                    }
+                }
+                if let (Stage::PpText | Stage::Cst, Some((pp, src))) = (stage, &pp_lines) {
+                    if v.location.file.as_ref().is_none_or(|f| *f == input_display) {
+                        v.location = source_location(&artifacts, pp, src, &v.location);
+                    }
                 }
-                continue;
             }
             let outcome = StageOutcome {
                 stage: stage.as_str().to_string(),
                 status: StageStatus::Ran,
-                violations: result.violations,
+                violations: stage_violations,
                 duration_ms: t0.elapsed().as_millis() as u64,
                 fail_ci: false,
             };
+            profile.record_phase(&format!("stage.{}", stage.as_str()), t0.elapsed());
             all.extend(outcome.violations.iter().cloned());
-            record_outcome(&input_path, &outcome);
+            record_outcome(input_path, &outcome);
+            outcomes.push(outcome);
             log_event(Ev::new(Event::StageDone, &input_display).with_stage(stage.as_str()));
         }
 
-        print_violations(&all, &input_path);
-        Ok(all.len())
+        let mut kept = suppressions.filter(all, &input_display);
+        kept.extend(suppressions.diagnostics(&rules));
+        Ok(FileLint {
+            violations: kept,
+            aborted: None,
+            outcomes,
+            parse_status: ParseStatus::Parsed,
+        })
+    }
+
+    fn report(&self, input_path: &Path, text: &str, violations: Vec<Violation>) -> Result<usize> {
+        let violations = match &self.changed {
+            Some(changed) => changed.filter(input_path, violations),
+            None => violations,
+        };
+        let violations = match &self.baseline {
+            Some(baseline) => match baseline.lock() {
+                Ok(mut mode) => mode.apply(&self.baseline_root(), input_path, text, violations),
+                Err(_) => violations,
+            },
+            None => violations,
+        };
+        if let Ok(mut reporter) = self.reporter.lock() {
+            reporter.report_file(input_path, &violations)?;
+        }
+        Ok(violations.len())
+    }
+
+    fn baseline_root(&self) -> PathBuf {
+        anchor_dir(self.cfg.plugin.config_dir.as_deref().unwrap_or(Path::new(".")))
+    }
+
+    fn read(&self, input: &Path) -> Result<(InputText, PathBuf)> {
+        match self.sources.get(input) {
+            Some(text) => Ok((text.clone(), input.to_path_buf())),
+            None => Ok(read_input(input)?),
+        }
     }
 
     fn has_enabled_rules(&self) -> bool {
         self.cfg.rule.iter().any(|r| r.enabled)
+            || self
+                .cfg
+                .overrides
+                .iter()
+                .any(|o| o.rule.iter().any(|r| r.enabled == Some(true)))
+    }
+
+    fn needs_plugin_host(&self) -> bool {
+        let native: HashSet<&str> = self
+            .cfg
+            .rule
+            .iter()
+            .filter(|r| r.native)
+            .map(|r| r.id.as_str())
+            .collect();
+        self.cfg.rule.iter().any(|r| r.enabled && !r.native)
+            || self.cfg.overrides.iter().any(|o| {
+                o.rule
+                    .iter()
+                    .any(|r| r.enabled == Some(true) && !native.contains(r.id.as_str()))
+            })
     }
 
     fn run_files_without_rules(&self, inputs: &[PathBuf]) -> Result<RunSummary> {
@@ -269,9 +686,37 @@ impl<'a> Pipeline<'a> {
     }
 
     fn run_file_without_rules(&self, input: &Path) -> Result<usize> {
-        let (_, input_path) = read_input(input).map_err(anyhow::Error::new)?;
-        print_violations(&[], &input_path);
-        Ok(0)
+        let (_, input_path) = self.read(input)?;
+        self.report(&input_path, "", Vec::new())
+    }
+}
+
+fn map_fix_to_source(artifacts: &ParseArtifacts, fix: &mut Fix) -> bool {
+    for edit in &mut fix.edits {
+        let Some((start, end)) = artifacts.pp_origins.range(edit.start, edit.end) else {
+            return false;
+        };
+        edit.start = start;
+        edit.end = end;
+    }
+    true
+}
+
+fn source_location(artifacts: &ParseArtifacts, pp: &LineMap, src: &LineMap, loc: &Location) -> Location {
+    let offset = |line: u32, col: u32| {
+        let start = pp.starts().get(line.max(1) as usize - 1)?;
+        artifacts.pp_origins.nearest(start + col.max(1) as usize - 1)
+    };
+    let (Some(start), Some(end)) = (offset(loc.line, loc.col), offset(loc.end_line, loc.end_col)) else {
+        return loc.clone();
+    };
+    let lines = src.to_lines(SpanBytes::new(start, end.max(start)));
+    Location {
+        line: lines.line,
+        col: lines.col,
+        end_line: lines.end_line,
+        end_col: lines.end_col,
+        file: loc.file.clone(),
     }
 }
 
@@ -342,22 +787,75 @@ struct StageRequest<'a> {
 #[derive(Default)]
 struct StageRuleSet {
     enabled: Vec<String>,
+    native: Vec<String>,
     disabled: Vec<String>,
+    options: Map<String, Value>,
+    native_options: HashMap<String, Map<String, Value>>,
+    severity: HashMap<String, Severity>,
+}
+
+fn run_native_rules(
+    stage: &Stage,
+    input_path: &Path,
+    artifacts: &ParseArtifacts,
+    rules: &StageRuleSet,
+) -> (Vec<Violation>, Vec<RuleTiming>) {
+    let registry = native::builtin();
+    let empty = Map::new();
+    let mut out = Vec::new();
+    let mut timings = Vec::with_capacity(rules.native.len());
+    for id in &rules.native {
+        let Some(rule) = registry.get(id) else {
+            out.push(Violation {
+                rule_id: NATIVE_UNKNOWN.to_string(),
+                severity: Severity::Error,
+                message: format!("native rule {} is not built into this sv-mint", id),
+                location: Location {
+                    line: 1,
+                    col: 1,
+                    end_line: 1,
+                    end_col: 1,
+                    file: None,
+                },
+                fixes: Vec::new(),
+            });
+            continue;
+        };
+        let ctx = StageContext {
+            stage: *stage,
+            path: input_path,
+            artifacts,
+            options: rules.native_options.get(id).unwrap_or(&empty),
+        };
+        let t0 = Instant::now();
+        out.extend(rule.check(&ctx));
+        timings.push(RuleTiming::new(id, t0.elapsed()));
+    }
+    (out, timings)
 }
 
-fn build_stage_rule_map(cfg: &Config) -> HashMap<Stage, StageRuleSet> {
+fn build_stage_rule_map(rules: &[RuleConfig]) -> HashMap<Stage, StageRuleSet> {
     let mut map: HashMap<Stage, StageRuleSet> = HashMap::new();
     const ALL_STAGES: [Stage; 4] = [Stage::RawText, Stage::PpText, Stage::Cst, Stage::Ast];
     for stage in ALL_STAGES {
         map.entry(stage).or_default();
     }
-    for rule in &cfg.rule {
+    for rule in rules {
         let entry = map.entry(rule.stage()).or_default();
-        if rule.enabled {
+        if rule.enabled && rule.native {
+            entry.native.push(rule.id.clone());
+            entry.native_options.insert(rule.id.clone(), rule.options.clone());
+        } else if rule.enabled {
             entry.enabled.push(rule.id.clone());
+            entry
+                .options
+                .insert(rule.id.clone(), Value::Object(rule.options.clone()));
         } else {
             entry.disabled.push(rule.id.clone());
         }
+        if let Some(sev) = rule.severity.as_deref().and_then(parse_severity) {
+            entry.severity.insert(rule.id.clone(), sev);
+        }
     }
     map
 }
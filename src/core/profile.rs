@@ -0,0 +1,207 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::str::FromStr;
+use std::time::Duration;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ProfileFormat {
+    #[default]
+    Table,
+    Json,
+}
+
+impl FromStr for ProfileFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "table" => Ok(Self::Table),
+            "json" => Ok(Self::Json),
+            other => Err(format!("unknown profile format: {other}")),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct RuleTiming {
+    pub rules: Vec<String>,
+    pub duration_ms: f64,
+}
+
+impl RuleTiming {
+    pub fn new(rule: &str, elapsed: Duration) -> Self {
+        Self {
+            rules: vec![rule.to_string()],
+            duration_ms: elapsed.as_secs_f64() * 1000.0,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+struct Stat {
+    calls: u64,
+    total_ms: f64,
+    max_ms: f64,
+}
+
+impl Stat {
+    fn add(&mut self, ms: f64) {
+        self.calls += 1;
+        self.total_ms += ms;
+        self.max_ms = self.max_ms.max(ms);
+    }
+}
+
+#[derive(Default)]
+pub struct Profile {
+    rules: BTreeMap<(String, String), Stat>,
+    phases: BTreeMap<String, Stat>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ProfileRow {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stage: Option<String>,
+    pub calls: u64,
+    pub total_ms: f64,
+    pub mean_ms: f64,
+    pub max_ms: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ProfileReport {
+    pub rules: Vec<ProfileRow>,
+    pub phases: Vec<ProfileRow>,
+}
+
+impl Profile {
+    pub fn record_rule(&mut self, stage: &str, timing: &RuleTiming) {
+        let key = (timing.rules.join("+"), stage.to_string());
+        self.rules.entry(key).or_default().add(timing.duration_ms);
+    }
+
+    pub fn record_phase(&mut self, phase: &str, elapsed: Duration) {
+        self.phases
+            .entry(phase.to_string())
+            .or_default()
+            .add(elapsed.as_secs_f64() * 1000.0);
+    }
+
+    pub fn merge(&mut self, other: Profile) {
+        for (key, stat) in other.rules {
+            merge_stat(self.rules.entry(key).or_default(), stat);
+        }
+        for (key, stat) in other.phases {
+            merge_stat(self.phases.entry(key).or_default(), stat);
+        }
+    }
+
+    pub fn report(&self) -> ProfileReport {
+        let mut rules: Vec<ProfileRow> = self
+            .rules
+            .iter()
+            .map(|((name, stage), stat)| row(name, Some(stage), stat))
+            .collect();
+        let mut phases: Vec<ProfileRow> = self.phases.iter().map(|(name, stat)| row(name, None, stat)).collect();
+        sort_rows(&mut rules);
+        sort_rows(&mut phases);
+        ProfileReport { rules, phases }
+    }
+}
+
+impl ProfileReport {
+    pub fn to_table(&self) -> String {
+        let name_w = self
+            .rules
+            .iter()
+            .chain(&self.phases)
+            .map(|r| r.name.len())
+            .chain([5])
+            .max()
+            .unwrap_or(5);
+        let stage_w = self
+            .rules
+            .iter()
+            .filter_map(|r| r.stage.as_ref().map(String::len))
+            .chain([5])
+            .max()
+            .unwrap_or(5);
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            "{:<name_w$}  {:<stage_w$}  {:>6}  {:>10}  {:>9}  {:>9}",
+            "RULE", "STAGE", "CALLS", "TOTAL(ms)", "MEAN(ms)", "MAX(ms)"
+        );
+        for r in &self.rules {
+            write_row(&mut out, r, name_w, stage_w);
+        }
+        let _ = writeln!(
+            out,
+            "\n{:<name_w$}  {:<stage_w$}  {:>6}  {:>10}  {:>9}  {:>9}",
+            "PHASE", "", "CALLS", "TOTAL(ms)", "MEAN(ms)", "MAX(ms)"
+        );
+        for r in &self.phases {
+            write_row(&mut out, r, name_w, stage_w);
+        }
+        out
+    }
+}
+
+fn merge_stat(dst: &mut Stat, src: Stat) {
+    dst.calls += src.calls;
+    dst.total_ms += src.total_ms;
+    dst.max_ms = dst.max_ms.max(src.max_ms);
+}
+
+fn row(name: &str, stage: Option<&String>, stat: &Stat) -> ProfileRow {
+    ProfileRow {
+        name: name.to_string(),
+        stage: stage.cloned(),
+        calls: stat.calls,
+        total_ms: stat.total_ms,
+        mean_ms: if stat.calls == 0 {
+            0.0
+        } else {
+            stat.total_ms / stat.calls as f64
+        },
+        max_ms: stat.max_ms,
+    }
+}
+
+fn sort_rows(rows: &mut [ProfileRow]) {
+    rows.sort_by(|a, b| b.total_ms.total_cmp(&a.total_ms).then_with(|| a.name.cmp(&b.name)));
+}
+
+fn write_row(out: &mut String, r: &ProfileRow, name_w: usize, stage_w: usize) {
+    let _ = writeln!(
+        out,
+        "{:<name_w$}  {:<stage_w$}  {:>6}  {:>10.3}  {:>9.3}  {:>9.3}",
+        r.name,
+        r.stage.as_deref().unwrap_or(""),
+        r.calls,
+        r.total_ms,
+        r.mean_ms,
+        r.max_ms
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_sorts_rules_by_total_time() {
+        let mut profile = Profile::default();
+        profile.record_rule("ast", &RuleTiming::new("fast", Duration::from_millis(1)));
+        profile.record_rule("ast", &RuleTiming::new("slow", Duration::from_millis(5)));
+        profile.record_rule("ast", &RuleTiming::new("slow", Duration::from_millis(3)));
+        let report = profile.report();
+        let names: Vec<&str> = report.rules.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, ["slow", "fast"]);
+        assert_eq!(report.rules[0].calls, 2);
+        assert!((report.rules[0].mean_ms - 4.0).abs() < 1e-9);
+        assert!((report.rules[0].max_ms - 5.0).abs() < 1e-9);
+    }
+}
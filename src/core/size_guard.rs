@@ -18,7 +18,7 @@ pub struct SizePolicy {
     pub is_required_stage: bool,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
 pub enum StageStatus {
     Ran,
@@ -26,7 +26,7 @@ pub enum StageStatus {
     Failed,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Clone, Debug)]
 pub struct StageOutcome {
     pub stage: String,
     pub status: StageStatus,
@@ -50,6 +50,7 @@ pub fn synth_violation_stage_skipped_size(stage: &str, severity: Severity, actua
             end_col: 1,
             file: None,
         },
+        fixes: Vec::new(),
     }
 }
 
@@ -71,6 +72,7 @@ pub fn enforce_request_size<T: Serialize>(stage: &str, req: &T, pol: &SizePolicy
                         end_col: 1,
                         file: None,
                     },
+                    fixes: Vec::new(),
                 }],
                 duration_ms: 0,
                 fail_ci: true,
@@ -121,6 +123,7 @@ pub fn enforce_response_size(stage: &str, response_bytes: usize, pol: &SizePolic
                 end_col: 1,
                 file: None,
             },
+            fixes: Vec::new(),
         };
         return Err(StageOutcome {
             stage: stage.to_string(),
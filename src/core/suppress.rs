@@ -0,0 +1,347 @@
+use crate::config::RuleConfig;
+use crate::types::{Location, Severity, Violation};
+use regex::Regex;
+use std::collections::HashSet;
+use std::sync::OnceLock;
+
+static DIRECTIVE_RE: OnceLock<Regex> = OnceLock::new();
+
+fn directive_re() -> &'static Regex {
+    DIRECTIVE_RE.get_or_init(|| {
+        Regex::new(
+            r"(?://|/\*)\s*sv-mint:\s*(disable-next-line|disable-line|disable-file|disable|enable)\b(?:\s*=\s*([A-Za-z0-9_.*]+(?:\s*,\s*[A-Za-z0-9_.*]+)*))?",
+        )
+        .unwrap()
+    })
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum DirectiveKind {
+    DisableNextLine,
+    DisableLine,
+    DisableFile,
+    Disable,
+    Enable,
+}
+
+impl DirectiveKind {
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "disable-next-line" => Some(DirectiveKind::DisableNextLine),
+            "disable-line" => Some(DirectiveKind::DisableLine),
+            "disable-file" => Some(DirectiveKind::DisableFile),
+            "disable" => Some(DirectiveKind::Disable),
+            "enable" => Some(DirectiveKind::Enable),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Directive {
+    kind: DirectiveKind,
+    rules: Vec<String>,
+    line: u32,
+    col: u32,
+    end_col: u32,
+    first_line: u32,
+    last_line: u32,
+    reenabled: Vec<(String, u32)>,
+    used: bool,
+}
+
+impl Directive {
+    fn matches_rule(&self, rule_id: &str) -> bool {
+        is_blanket(&self.rules) || self.rules.iter().any(|r| r == rule_id)
+    }
+
+    fn suppresses(&self, rule_id: &str, line: u32) -> bool {
+        (self.first_line..=self.last_line).contains(&line)
+            && self.matches_rule(rule_id)
+            && !self.reenabled.iter().any(|(r, from)| r == rule_id && line >= *from)
+    }
+
+    fn location(&self) -> Location {
+        Location {
+            line: self.line,
+            col: self.col,
+            end_line: self.line,
+            end_col: self.end_col,
+            file: None,
+        }
+    }
+}
+
+pub struct Suppressions {
+    directives: Vec<Directive>,
+}
+
+impl Suppressions {
+    pub fn scan(text: &str) -> Self {
+        let mut directives: Vec<Directive> = Vec::new();
+        let mut open: Vec<usize> = Vec::new();
+        let last_line = text.lines().count().max(1) as u32;
+        for (idx, line_text) in text.lines().enumerate() {
+            let line = idx as u32 + 1;
+            for cap in directive_re().captures_iter(line_text) {
+                let Some(kind) = DirectiveKind::parse(&cap[1]) else {
+                    continue;
+                };
+                let whole = cap.get(0).unwrap();
+                let rules: Vec<String> = cap
+                    .get(2)
+                    .map(|m| {
+                        m.as_str()
+                            .split(',')
+                            .map(|s| s.trim().to_string())
+                            .filter(|s| !s.is_empty())
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                let (first_line, scope_end) = match kind {
+                    DirectiveKind::DisableNextLine => (line + 1, line + 1),
+                    DirectiveKind::DisableLine => (line, line),
+                    DirectiveKind::DisableFile => (1, last_line),
+                    DirectiveKind::Disable => (line, last_line),
+                    DirectiveKind::Enable => (line, line),
+                };
+                let directive = Directive {
+                    kind,
+                    rules,
+                    line,
+                    col: whole.start() as u32 + 1,
+                    end_col: whole.end() as u32 + 1,
+                    first_line,
+                    last_line: scope_end,
+                    reenabled: Vec::new(),
+                    used: false,
+                };
+                if kind == DirectiveKind::Enable {
+                    close_regions(&mut directives, &mut open, &directive);
+                }
+                if kind == DirectiveKind::Disable {
+                    open.push(directives.len());
+                }
+                directives.push(directive);
+            }
+        }
+        Self { directives }
+    }
+
+    pub fn filter(&mut self, violations: Vec<Violation>, input_path: &str) -> Vec<Violation> {
+        if self.directives.is_empty() {
+            return violations;
+        }
+        let mut out = Vec::with_capacity(violations.len());
+        for v in violations {
+            if !is_suppressible(&v, input_path) {
+                out.push(v);
+                continue;
+            }
+            let mut suppressed = false;
+            for d in &mut self.directives {
+                if d.kind == DirectiveKind::Enable {
+                    continue;
+                }
+                if d.suppresses(&v.rule_id, v.location.line) {
+                    d.used = true;
+                    suppressed = true;
+                }
+            }
+            if !suppressed {
+                out.push(v);
+            }
+        }
+        out
+    }
+
+    pub fn diagnostics(&self, rules: &[RuleConfig]) -> Vec<Violation> {
+        let known: HashSet<&str> = rules.iter().map(|r| r.id.as_str()).collect();
+        let enabled: HashSet<&str> = rules.iter().filter(|r| r.enabled).map(|r| r.id.as_str()).collect();
+        let mut out = Vec::new();
+        for d in &self.directives {
+            for rule in &d.rules {
+                if rule != "*" && !known.contains(rule.as_str()) {
+                    out.push(Violation {
+                        rule_id: "sys.suppression.unknown".to_string(),
+                        severity: Severity::Warning,
+                        message: format!("suppression references unknown rule {}", rule),
+                        location: d.location(),
+                        fixes: Vec::new(),
+                    });
+                }
+            }
+            if d.kind == DirectiveKind::Enable || d.used {
+                continue;
+            }
+            let active = d.rules.is_empty() || d.rules.iter().any(|r| r == "*" || enabled.contains(r.as_str()));
+            if active {
+                out.push(Violation {
+                    rule_id: "sys.suppression.unused".to_string(),
+                    severity: Severity::Warning,
+                    message: format!("unused suppression {}", describe(d)),
+                    location: d.location(),
+                    fixes: Vec::new(),
+                });
+            }
+        }
+        out
+    }
+}
+
+fn close_regions(directives: &mut [Directive], open: &mut Vec<usize>, enable: &Directive) {
+    open.retain(|&idx| {
+        let region = &mut directives[idx];
+        let closes = is_blanket(&enable.rules)
+            || (!is_blanket(&region.rules) && region.rules.iter().all(|r| enable.rules.contains(r)));
+        if closes {
+            region.last_line = enable.line;
+            return false;
+        }
+        for rule in &enable.rules {
+            if region.matches_rule(rule) {
+                region.reenabled.push((rule.clone(), enable.line + 1));
+            }
+        }
+        true
+    });
+}
+
+fn is_blanket(rules: &[String]) -> bool {
+    rules.is_empty() || rules.iter().any(|r| r == "*")
+}
+
+fn is_suppressible(v: &Violation, input_path: &str) -> bool {
+    if v.rule_id.starts_with("sys.") {
+        return false;
+    }
+    match v.location.file.as_deref() {
+        None => true,
+        Some(file) => file == input_path,
+    }
+}
+
+fn describe(d: &Directive) -> String {
+    let kind = match d.kind {
+        DirectiveKind::DisableNextLine => "disable-next-line",
+        DirectiveKind::DisableLine => "disable-line",
+        DirectiveKind::DisableFile => "disable-file",
+        DirectiveKind::Disable => "disable",
+        DirectiveKind::Enable => "enable",
+    };
+    if d.rules.is_empty() {
+        kind.to_string()
+    } else {
+        format!("{}={}", kind, d.rules.join(","))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Stage;
+
+    fn violation(rule_id: &str, line: u32) -> Violation {
+        Violation {
+            rule_id: rule_id.to_string(),
+            severity: Severity::Warning,
+            message: String::new(),
+            location: Location {
+                line,
+                col: 1,
+                end_line: line,
+                end_col: 2,
+                file: None,
+            },
+            fixes: Vec::new(),
+        }
+    }
+
+    fn rules(ids: &[&str]) -> Vec<RuleConfig> {
+        ids.iter()
+            .map(|id| RuleConfig {
+                id: id.to_string(),
+                script: format!("{id}.raw.py"),
+                native: false,
+                host: None,
+                stage: Some(Stage::RawText),
+                enabled: true,
+                severity: None,
+                options: Default::default(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn line_and_next_line_directives() {
+        let text = "// sv-mint: disable-next-line=a\nwire x;\nwire y; // sv-mint: disable-line=b\nwire z;\n";
+        let mut sup = Suppressions::scan(text);
+        let kept = sup.filter(
+            vec![
+                violation("a", 2),
+                violation("b", 2),
+                violation("b", 3),
+                violation("a", 4),
+            ],
+            "top.sv",
+        );
+        let kept: Vec<_> = kept.iter().map(|v| (v.rule_id.as_str(), v.location.line)).collect();
+        assert_eq!(kept, vec![("b", 2), ("a", 4)]);
+        assert!(sup.diagnostics(&rules(&["a", "b"])).is_empty());
+    }
+
+    #[test]
+    fn block_regions_and_file_directives() {
+        let text = "/* sv-mint: disable-file=c */\n// sv-mint: disable=a\nx\ny\n// sv-mint: enable=a\nz\n";
+        let mut sup = Suppressions::scan(text);
+        let kept = sup.filter(
+            vec![
+                violation("a", 3),
+                violation("a", 4),
+                violation("a", 6),
+                violation("c", 6),
+            ],
+            "top.sv",
+        );
+        assert_eq!(kept.len(), 1);
+        assert_eq!(kept[0].location.line, 6);
+        assert_eq!(kept[0].rule_id, "a");
+    }
+
+    #[test]
+    fn targeted_enable_only_reenables_named_rules() {
+        let text = "// sv-mint: disable\nx\n// sv-mint: disable=b,c\n// sv-mint: enable=a,b\ny\n";
+        let mut sup = Suppressions::scan(text);
+        let kept = sup.filter(
+            vec![
+                violation("a", 2),
+                violation("b", 2),
+                violation("a", 5),
+                violation("b", 5),
+                violation("c", 5),
+            ],
+            "top.sv",
+        );
+        let kept: Vec<_> = kept.iter().map(|v| (v.rule_id.as_str(), v.location.line)).collect();
+        assert_eq!(kept, vec![("a", 5), ("b", 5)]);
+    }
+
+    #[test]
+    fn reports_unused_and_unknown_suppressions() {
+        let text = "// sv-mint: disable-line=a\n// sv-mint: disable-line=missing\n";
+        let mut sup = Suppressions::scan(text);
+        let kept = sup.filter(vec![violation("sys.parse.failed", 1)], "top.sv");
+        assert_eq!(kept.len(), 1);
+        let diags = sup.diagnostics(&rules(&["a"]));
+        let ids: Vec<_> = diags.iter().map(|v| (v.rule_id.as_str(), v.location.line)).collect();
+        assert_eq!(ids, vec![("sys.suppression.unused", 1), ("sys.suppression.unknown", 2)]);
+    }
+
+    #[test]
+    fn ignores_violations_in_other_files() {
+        let mut sup = Suppressions::scan("// sv-mint: disable-file\n");
+        let mut v = violation("a", 1);
+        v.location.file = Some("inc.svh".to_string());
+        assert_eq!(sup.filter(vec![v], "top.sv").len(), 1);
+    }
+}
@@ -18,12 +18,28 @@ pub enum Severity {
     Info,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct TextEdit {
+    pub start: usize,
+    pub end: usize,
+    pub replacement: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Fix {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub edits: Vec<TextEdit>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Violation {
     pub rule_id: String,
     pub severity: Severity,
     pub message: String,
     pub location: Location,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fixes: Vec<Fix>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -0,0 +1,116 @@
+use crate::sv::includes::resolve_includes;
+use crate::sv::model::SvParserCfg;
+use std::collections::{BTreeSet, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+pub const POLL_INTERVAL_MS: u64 = 300;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct Stamp {
+    modified: Option<SystemTime>,
+    len: u64,
+}
+
+fn stamp(path: &Path) -> Option<Stamp> {
+    let meta = fs::metadata(path).ok()?;
+    Some(Stamp {
+        modified: meta.modified().ok(),
+        len: meta.len(),
+    })
+}
+
+#[derive(Default)]
+pub struct FileWatcher {
+    stamps: HashMap<PathBuf, Option<Stamp>>,
+    includes: HashMap<PathBuf, BTreeSet<PathBuf>>,
+    svparser: HashMap<PathBuf, SvParserCfg>,
+}
+
+impl FileWatcher {
+    pub fn track(&mut self, input: &Path, svparser: &SvParserCfg) {
+        self.svparser.insert(input.to_path_buf(), svparser.clone());
+        self.refresh(input);
+    }
+
+    pub fn inputs(&self) -> usize {
+        self.svparser.len()
+    }
+
+    pub fn poll(&mut self) -> Vec<PathBuf> {
+        let changed: Vec<PathBuf> = self
+            .stamps
+            .iter()
+            .filter(|(path, old)| stamp(path) != **old)
+            .map(|(path, _)| path.clone())
+            .collect();
+        let mut affected: BTreeSet<PathBuf> = BTreeSet::new();
+        for path in &changed {
+            self.stamps.insert(path.clone(), stamp(path));
+            if self.svparser.contains_key(path) {
+                affected.insert(path.clone());
+            }
+            for (input, deps) in &self.includes {
+                if deps.contains(path) {
+                    affected.insert(input.clone());
+                }
+            }
+        }
+        for input in &affected {
+            self.refresh(input);
+        }
+        affected.into_iter().collect()
+    }
+
+    fn refresh(&mut self, input: &Path) {
+        self.stamps.insert(input.to_path_buf(), stamp(input));
+        let Some(cfg) = self.svparser.get(input) else { return };
+        let mut deps = BTreeSet::new();
+        if !cfg.ignore_include {
+            let text = fs::read_to_string(input).unwrap_or_default();
+            for include in resolve_includes(input, &text, &cfg.include_paths) {
+                if let Some(path) = include.path {
+                    deps.insert(path);
+                }
+            }
+        }
+        for dep in &deps {
+            if !self.stamps.contains_key(dep) {
+                self.stamps.insert(dep.clone(), stamp(dep));
+            }
+        }
+        self.includes.insert(input.to_path_buf(), deps);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn header_changes_mark_including_files() {
+        let tmp = tempfile::tempdir().unwrap();
+        let header = tmp.path().join("defs.svh");
+        let top = tmp.path().join("top.sv");
+        let other = tmp.path().join("other.sv");
+        fs::write(&header, "`define W 8\n").unwrap();
+        fs::write(&top, "`include \"defs.svh\"\nmodule top;\nendmodule\n").unwrap();
+        fs::write(&other, "module other;\nendmodule\n").unwrap();
+        let cfg = SvParserCfg {
+            ignore_include: false,
+            ..SvParserCfg::default()
+        };
+        let mut watcher = FileWatcher::default();
+        watcher.track(&top, &cfg);
+        watcher.track(&other, &cfg);
+        assert!(watcher.poll().is_empty());
+
+        fs::write(&header, "`define W 16\n").unwrap();
+        assert_eq!(watcher.poll(), vec![top.clone()]);
+        assert!(watcher.poll().is_empty());
+
+        fs::write(&other, "module other2;\nendmodule\n").unwrap();
+        assert_eq!(watcher.poll(), vec![other]);
+    }
+}
@@ -8,6 +8,7 @@ pub enum Event {
     PluginExitNonzero,
     PluginError,
     PluginStderr,
+    PluginRestart,
     ParsePreprocessStart,
     ParsePreprocessDone,
     ParseParseStart,
@@ -26,6 +27,7 @@ impl Event {
             Event::PluginExitNonzero => "plugin_exit_nonzero",
             Event::PluginError => "plugin_error",
             Event::PluginStderr => "plugin_stderr",
+            Event::PluginRestart => "plugin_restart",
             Event::ParsePreprocessStart => "parse_preprocess_start",
             Event::ParsePreprocessDone => "parse_preprocess_done",
             Event::ParseParseStart => "parse_parse_start",
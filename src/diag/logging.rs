@@ -53,7 +53,8 @@ fn should_emit(ev: Event) -> bool {
             | Event::PluginTimeout
             | Event::PluginExitNonzero
             | Event::PluginError
-            | Event::PluginStderr => t.plugin,
+            | Event::PluginStderr
+            | Event::PluginRestart => t.plugin,
             Event::ParsePreprocessStart
             | Event::ParsePreprocessDone
             | Event::ParseParseStart
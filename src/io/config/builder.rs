@@ -0,0 +1,126 @@
+use super::normalize::{infer_rule_stages, normalize_rule_scripts};
+use super::validate::{validate_config, validate_rule_script_paths};
+use super::{Config, RuleConfig};
+use crate::errors::ConfigError;
+use crate::types::Stage;
+use serde_json::Value as JsonValue;
+use std::env;
+use std::path::PathBuf;
+
+pub struct ConfigBuilder {
+    cfg: Config,
+    base_dir: Option<PathBuf>,
+}
+
+impl Default for ConfigBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ConfigBuilder {
+    pub fn new() -> Self {
+        Self {
+            cfg: Config::default(),
+            base_dir: None,
+        }
+    }
+
+    pub fn base_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.base_dir = Some(dir.into());
+        self
+    }
+
+    pub fn plugin_root(mut self, root: impl Into<PathBuf>) -> Self {
+        self.cfg.plugin.root = Some(root.into().to_string_lossy().into_owned());
+        self
+    }
+
+    pub fn plugin_command<I, S>(mut self, cmd: impl Into<String>, args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.cfg.plugin.cmd = cmd.into();
+        self.cfg.plugin.args = args.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn stages(mut self, enabled: impl IntoIterator<Item = Stage>) -> Self {
+        self.cfg.stages.enabled = enabled.into_iter().collect();
+        self.cfg.stages.required.retain(|s| self.cfg.stages.enabled.contains(s));
+        self
+    }
+
+    pub fn include_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.cfg.svparser.ignore_include = false;
+        self.cfg
+            .svparser
+            .include_paths
+            .push(path.into().to_string_lossy().into_owned());
+        self
+    }
+
+    pub fn define(mut self, define: impl Into<String>) -> Self {
+        self.cfg.svparser.defines.push(define.into());
+        self
+    }
+
+    pub fn rule(self, id: impl Into<String>) -> Self {
+        self.rule_config(RuleConfig {
+            id: id.into(),
+            script: String::new(),
+            native: false,
+            host: None,
+            stage: None,
+            enabled: true,
+            severity: None,
+            options: Default::default(),
+        })
+    }
+
+    pub fn native_rule(self, id: impl Into<String>) -> Self {
+        self.rule_config(RuleConfig {
+            id: id.into(),
+            script: String::new(),
+            native: true,
+            host: None,
+            stage: None,
+            enabled: true,
+            severity: None,
+            options: Default::default(),
+        })
+    }
+
+    pub fn rule_config(mut self, rule: RuleConfig) -> Self {
+        self.cfg.rule.push(rule);
+        self
+    }
+
+    pub fn severity(mut self, id: &str, severity: impl Into<String>) -> Self {
+        if let Some(rule) = self.cfg.rule.iter_mut().rev().find(|r| r.id == id) {
+            rule.severity = Some(severity.into());
+        }
+        self
+    }
+
+    pub fn option(mut self, id: &str, key: impl Into<String>, value: impl Into<JsonValue>) -> Self {
+        if let Some(rule) = self.cfg.rule.iter_mut().rev().find(|r| r.id == id) {
+            rule.options.insert(key.into(), value.into());
+        }
+        self
+    }
+
+    pub fn build(self) -> Result<Config, ConfigError> {
+        let mut cfg = self.cfg;
+        let base_dir = match self.base_dir {
+            Some(dir) => dir,
+            None => env::current_dir().map_err(|e| ConfigError::IoFailed { detail: e.to_string() })?,
+        };
+        normalize_rule_scripts(&mut cfg, &base_dir)?;
+        infer_rule_stages(&mut cfg.rule)?;
+        validate_config(&cfg)?;
+        validate_rule_script_paths(&cfg)?;
+        Ok(cfg)
+    }
+}
@@ -0,0 +1,80 @@
+use std::collections::HashMap;
+use std::env;
+use std::path::{Path, PathBuf};
+
+pub const CONFIG_FILE_NAME: &str = "sv-mint.toml";
+
+pub fn discover_config(start: &Path) -> Option<PathBuf> {
+    let start = if start.is_absolute() {
+        start.to_path_buf()
+    } else {
+        env::current_dir().ok()?.join(start)
+    };
+    let mut dir = if start.is_dir() {
+        start.as_path()
+    } else {
+        start.parent()?
+    };
+    loop {
+        let candidate = dir.join(CONFIG_FILE_NAME);
+        if candidate.is_file() {
+            return Some(candidate);
+        }
+        if dir.join(".git").exists() {
+            return None;
+        }
+        dir = dir.parent()?;
+    }
+}
+
+pub fn group_by_config(inputs: Vec<PathBuf>) -> Vec<(Option<PathBuf>, Vec<PathBuf>)> {
+    let mut groups: Vec<(Option<PathBuf>, Vec<PathBuf>)> = Vec::new();
+    let mut index: HashMap<Option<PathBuf>, usize> = HashMap::new();
+    let mut by_dir: HashMap<PathBuf, Option<PathBuf>> = HashMap::new();
+    for input in inputs {
+        let dir = input.parent().map(Path::to_path_buf).unwrap_or_default();
+        let config = by_dir.entry(dir).or_insert_with(|| discover_config(&input)).clone();
+        let slot = *index.entry(config.clone()).or_insert_with(|| {
+            groups.push((config, Vec::new()));
+            groups.len() - 1
+        });
+        groups[slot].1.push(input);
+    }
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::tempdir;
+
+    #[test]
+    fn finds_nearest_config_below_repo_root() {
+        let tmp = tempdir().unwrap();
+        let repo = tmp.path().join("repo");
+        fs::create_dir_all(repo.join(".git")).unwrap();
+        fs::create_dir_all(repo.join("ip/a/rtl")).unwrap();
+        fs::create_dir_all(repo.join("ip/b/rtl")).unwrap();
+        fs::write(repo.join(CONFIG_FILE_NAME), "").unwrap();
+        fs::write(repo.join("ip/a").join(CONFIG_FILE_NAME), "").unwrap();
+        fs::write(tmp.path().join(CONFIG_FILE_NAME), "").unwrap();
+        let a = repo.join("ip/a/rtl/x.sv");
+        let b = repo.join("ip/b/rtl/y.sv");
+        let c = repo.join("ip/a/rtl/z.sv");
+        assert_eq!(discover_config(&a), Some(repo.join("ip/a").join(CONFIG_FILE_NAME)));
+        assert_eq!(discover_config(&b), Some(repo.join(CONFIG_FILE_NAME)));
+
+        let groups = group_by_config(vec![a.clone(), b.clone(), c.clone()]);
+        assert_eq!(
+            groups,
+            vec![
+                (Some(repo.join("ip/a").join(CONFIG_FILE_NAME)), vec![a, c]),
+                (Some(repo.join(CONFIG_FILE_NAME)), vec![b]),
+            ]
+        );
+
+        fs::remove_file(repo.join(CONFIG_FILE_NAME)).unwrap();
+        assert_eq!(discover_config(&repo.join("ip/b/rtl")), None);
+    }
+}
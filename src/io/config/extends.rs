@@ -0,0 +1,278 @@
+use super::normalize::to_abs;
+use crate::errors::ConfigError;
+use std::fs;
+use std::path::{Path, PathBuf};
+use toml::value::Table;
+use toml::Value;
+
+const MAX_EXTENDS_DEPTH: usize = 16;
+
+pub(super) struct MergedConfig {
+    pub value: Value,
+    pub override_dirs: Vec<PathBuf>,
+}
+
+pub(super) fn load_merged(path: &Path) -> Result<MergedConfig, ConfigError> {
+    let mut stack = Vec::new();
+    load_layer(path, true, &mut stack)
+}
+
+fn load_layer(path: &Path, is_root: bool, stack: &mut Vec<PathBuf>) -> Result<MergedConfig, ConfigError> {
+    let key = fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+    if stack.contains(&key) {
+        return Err(ConfigError::InvalidValue {
+            detail: format!("config {} extends itself", path.display()),
+        });
+    }
+    if stack.len() >= MAX_EXTENDS_DEPTH {
+        return Err(ConfigError::InvalidValue {
+            detail: format!("config {} extends too deeply", path.display()),
+        });
+    }
+    let text = fs::read_to_string(path).map_err(|e| ConfigError::IoFailed {
+        detail: format!("{} ({})", path.display(), e),
+    })?;
+    let mut value: Value = text
+        .parse()
+        .map_err(|e: toml::de::Error| ConfigError::InvalidToml { detail: e.to_string() })?;
+    let base_dir = path
+        .parent()
+        .map(Path::to_path_buf)
+        .unwrap_or_else(|| PathBuf::from("."));
+    let table = value.as_table_mut().ok_or_else(|| ConfigError::InvalidToml {
+        detail: format!("{} is not a table", path.display()),
+    })?;
+    let extends = take_extends(table, path)?;
+    if !is_root {
+        absolutize_layer(table, &base_dir);
+    }
+    let own_overrides = table.get("override").and_then(Value::as_array).map_or(0, Vec::len);
+
+    stack.push(key);
+    let mut merged = MergedConfig {
+        value: Value::Table(Table::new()),
+        override_dirs: Vec::new(),
+    };
+    for parent in extends {
+        let parent_path = to_abs(&base_dir, &parent);
+        let layer = load_layer(&parent_path, false, stack)?;
+        merge_value(&mut merged.value, layer.value);
+        merged.override_dirs.extend(layer.override_dirs);
+    }
+    stack.pop();
+
+    merge_value(&mut merged.value, value);
+    merged
+        .override_dirs
+        .extend(std::iter::repeat_n(base_dir, own_overrides));
+    Ok(merged)
+}
+
+fn take_extends(table: &mut Table, path: &Path) -> Result<Vec<String>, ConfigError> {
+    match table.remove("extends") {
+        None => Ok(Vec::new()),
+        Some(Value::String(s)) => Ok(vec![s]),
+        Some(Value::Array(items)) => items
+            .into_iter()
+            .map(|item| match item {
+                Value::String(s) => Ok(s),
+                _ => Err(ConfigError::InvalidValue {
+                    detail: format!("{}: extends entries must be strings", path.display()),
+                }),
+            })
+            .collect(),
+        Some(_) => Err(ConfigError::InvalidValue {
+            detail: format!("{}: extends must be a string or array of strings", path.display()),
+        }),
+    }
+}
+
+fn absolutize_layer(table: &mut Table, base_dir: &Path) {
+    let mut roots = Vec::new();
+    if let Some(plugin) = table.get_mut("plugin").and_then(Value::as_table_mut) {
+        if let Some(Value::String(root)) = plugin.get_mut("root") {
+            *root = to_abs(base_dir, root).to_string_lossy().into_owned();
+            roots.push(PathBuf::from(root.as_str()));
+        }
+        if let Some(Value::Array(paths)) = plugin.get_mut("search_paths") {
+            for entry in paths.iter_mut() {
+                if let Value::String(p) = entry {
+                    *p = to_abs(base_dir, p).to_string_lossy().into_owned();
+                    roots.push(PathBuf::from(p.as_str()));
+                }
+            }
+        }
+    }
+    if roots.is_empty() {
+        roots.push(base_dir.join("plugins"));
+    }
+    let Some(rules) = table.get_mut("rule").and_then(Value::as_array_mut) else {
+        return;
+    };
+    for rule in rules.iter_mut().filter_map(Value::as_table_mut) {
+        if rule.get("native").and_then(Value::as_bool) == Some(true) {
+            continue;
+        }
+        let script = match rule.get("script") {
+            Some(Value::String(s)) if !s.trim().is_empty() => s.clone(),
+            Some(_) => continue,
+            None => {
+                let Some(id) = rule.get("id").and_then(Value::as_str) else {
+                    continue;
+                };
+                let found = roots.iter().find_map(|root| {
+                    ["raw", "pp", "cst", "ast"]
+                        .iter()
+                        .map(|stage| root.join(format!("{}.{}.py", id, stage)))
+                        .find(|p| p.exists())
+                });
+                if let Some(found) = found {
+                    rule.insert(
+                        "script".to_string(),
+                        Value::String(found.to_string_lossy().into_owned()),
+                    );
+                }
+                continue;
+            }
+        };
+        if Path::new(&script).is_absolute() {
+            continue;
+        }
+        let resolved = roots
+            .iter()
+            .map(|root| root.join(&script))
+            .find(|p| p.exists())
+            .unwrap_or_else(|| base_dir.join(&script));
+        rule.insert(
+            "script".to_string(),
+            Value::String(resolved.to_string_lossy().into_owned()),
+        );
+    }
+}
+
+fn merge_value(base: &mut Value, layer: Value) {
+    match (base, layer) {
+        (Value::Table(base), Value::Table(layer)) => {
+            for (key, value) in layer {
+                match (key.as_str(), base.get_mut(&key)) {
+                    ("rule", Some(Value::Array(existing))) => merge_rules(existing, value),
+                    ("override", Some(Value::Array(existing))) => {
+                        if let Value::Array(items) = value {
+                            existing.extend(items);
+                        }
+                    }
+                    (_, Some(existing @ Value::Table(_))) if value.is_table() => merge_value(existing, value),
+                    _ => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, layer) => *base = layer,
+    }
+}
+
+fn merge_rules(existing: &mut Vec<Value>, layer: Value) {
+    let Value::Array(items) = layer else { return };
+    for item in items {
+        let id = item.get("id").and_then(Value::as_str).map(str::to_string);
+        let slot = id.as_deref().and_then(|id| {
+            existing
+                .iter_mut()
+                .find(|r| r.get("id").and_then(Value::as_str) == Some(id))
+        });
+        match slot {
+            Some(slot) => merge_value(slot, item),
+            None => existing.push(item),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn merges_rules_by_id_and_resolves_paths_per_file() {
+        let tmp = tempdir().unwrap();
+        let root = tmp.path();
+        fs::create_dir_all(root.join("base/plugins")).unwrap();
+        fs::write(root.join("base/plugins/shared.raw.py"), "").unwrap();
+        fs::write(
+            root.join("base/base.toml"),
+            r#"
+[logging]
+level = "warn"
+show_stage_events = true
+
+[[rule]]
+id = "shared"
+severity = "error"
+
+[rule.options]
+max = 1
+keep = true
+
+[[rule]]
+id = "other"
+script = "extra/other.raw.py"
+
+[[override]]
+paths = ["gen/**"]
+"#,
+        )
+        .unwrap();
+        fs::create_dir_all(root.join("ip")).unwrap();
+        fs::write(
+            root.join("ip/sv-mint.toml"),
+            r#"
+extends = ["../base/base.toml"]
+
+[logging]
+level = "debug"
+
+[[rule]]
+id = "shared"
+enabled = false
+
+[rule.options]
+max = 2
+
+[[override]]
+paths = ["tb/**"]
+"#,
+        )
+        .unwrap();
+        let merged = load_merged(&root.join("ip/sv-mint.toml")).unwrap();
+        let value = merged.value;
+        assert_eq!(value["logging"]["level"].as_str(), Some("debug"));
+        assert_eq!(value["logging"]["show_stage_events"].as_bool(), Some(true));
+        let rules = value["rule"].as_array().unwrap();
+        assert_eq!(rules.len(), 2);
+        assert_eq!(rules[0]["severity"].as_str(), Some("error"));
+        assert_eq!(rules[0]["enabled"].as_bool(), Some(false));
+        assert_eq!(rules[0]["options"]["max"].as_integer(), Some(2));
+        assert_eq!(rules[0]["options"]["keep"].as_bool(), Some(true));
+        assert_eq!(
+            fs::canonicalize(rules[0]["script"].as_str().unwrap()).unwrap(),
+            fs::canonicalize(root.join("base/plugins/shared.raw.py")).unwrap()
+        );
+        assert_eq!(
+            Path::new(rules[1]["script"].as_str().unwrap()),
+            root.join("ip/../base/extra/other.raw.py")
+        );
+        assert_eq!(value["override"].as_array().unwrap().len(), 2);
+        assert_eq!(merged.override_dirs, vec![root.join("ip/../base"), root.join("ip")]);
+        assert!(value.get("extends").is_none());
+    }
+
+    #[test]
+    fn rejects_extends_cycles() {
+        let tmp = tempdir().unwrap();
+        fs::write(tmp.path().join("a.toml"), "extends = \"b.toml\"\n").unwrap();
+        fs::write(tmp.path().join("b.toml"), "extends = [\"a.toml\"]\n").unwrap();
+        let err = load_merged(&tmp.path().join("a.toml")).err().unwrap();
+        assert!(err.to_string().contains("extends itself"));
+    }
+}
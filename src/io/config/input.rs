@@ -1,14 +1,24 @@
 use crate::errors::ConfigError;
 use crate::textutil::{normalize_lf, strip_bom};
 use std::fs;
+use std::io::{self, Read};
 use std::path::{Path, PathBuf};
 
+pub const STDIN_INPUT: &str = "-";
+
 #[derive(Clone)]
 pub struct InputText {
     pub raw: String,
     pub normalized: String,
 }
 
+impl InputText {
+    pub fn new(raw: String) -> Self {
+        let normalized = normalize_lf(strip_bom(raw.clone()));
+        Self { raw, normalized }
+    }
+}
+
 pub fn read_input(path: &Path) -> Result<(InputText, PathBuf), ConfigError> {
     let bytes = fs::read(path).map_err(|_| ConfigError::NotFound {
         path: path.display().to_string(),
@@ -17,6 +27,17 @@ pub fn read_input(path: &Path) -> Result<(InputText, PathBuf), ConfigError> {
         path: path.display().to_string(),
         source: None,
     })?;
-    let normalized = normalize_lf(strip_bom(raw.clone()));
-    Ok((InputText { raw, normalized }, path.to_path_buf()))
+    Ok((InputText::new(raw), path.to_path_buf()))
+}
+
+pub fn read_stdin(path: &Path) -> Result<InputText, ConfigError> {
+    let mut bytes = Vec::new();
+    io::stdin().read_to_end(&mut bytes).map_err(|e| ConfigError::IoFailed {
+        detail: format!("stdin: {}", e),
+    })?;
+    let raw = String::from_utf8(bytes).map_err(|_| ConfigError::InvalidUtf8 {
+        path: path.display().to_string(),
+        source: None,
+    })?;
+    Ok(InputText::new(raw))
 }
@@ -1,9 +1,10 @@
+use super::discover::{discover_config, CONFIG_FILE_NAME};
+use super::extends::load_merged;
 use super::normalize::{infer_rule_stages, normalize_rule_scripts};
 use super::validate::{validate_config, validate_rule_script_paths};
 use super::Config;
 use crate::errors::ConfigError;
 use std::env;
-use std::fs;
 use std::path::{Path, PathBuf};
 
 pub fn resolve_path(opt: Option<PathBuf>) -> Result<PathBuf, ConfigError> {
@@ -13,13 +14,13 @@ pub fn resolve_path(opt: Option<PathBuf>) -> Result<PathBuf, ConfigError> {
             path: p.display().to_string(),
         }),
         None => {
-            let p = PathBuf::from("sv-mint.toml");
-            if !p.exists() {
-                return Err(ConfigError::NotFound {
-                    path: p.display().to_string(),
-                });
+            let p = PathBuf::from(CONFIG_FILE_NAME);
+            if p.exists() {
+                return Ok(p);
             }
-            Ok(p)
+            discover_config(Path::new(".")).ok_or_else(|| ConfigError::NotFound {
+                path: p.display().to_string(),
+            })
         }
     }
 }
@@ -38,15 +39,22 @@ pub fn load_from_path(opt: Option<PathBuf>) -> Result<(Config, PathBuf), ConfigE
     } else {
         cwd.join(path_rel)
     };
-    let cfg_text = fs::read_to_string(&path).map_err(|e| ConfigError::IoFailed {
-        detail: format!("{} ({})", path.display(), e),
-    })?;
-    let mut cfg = load(&cfg_text)?;
+    let merged = load_merged(&path)?;
+    warn_on_unknown_keys(&merged.value);
+    let mut cfg: Config = merged
+        .value
+        .try_into()
+        .map_err(|e: toml::de::Error| ConfigError::InvalidToml { detail: e.to_string() })?;
+    for (entry, dir) in cfg.overrides.iter_mut().zip(merged.override_dirs) {
+        entry.base_dir = Some(dir);
+    }
+    for entry in &mut cfg.overrides {
+        entry.compile()?;
+    }
     let base_dir = path
         .parent()
         .map(Path::to_path_buf)
         .unwrap_or_else(|| PathBuf::from("."));
-    warn_on_unknown_keys(&cfg_text);
     normalize_rule_scripts(&mut cfg, &base_dir)?;
     infer_rule_stages(&mut cfg.rule)?;
     validate_config(&cfg)?;
@@ -54,17 +62,17 @@ pub fn load_from_path(opt: Option<PathBuf>) -> Result<(Config, PathBuf), ConfigE
     Ok((cfg, path))
 }
 
-pub(super) fn warn_on_unknown_keys(cfg_text: &str) {
-    if let Ok(table) = cfg_text.parse::<toml::Value>() {
-        if let Some(obj) = table.as_table() {
-            for (k, v) in obj {
-                match k.as_str() {
-                    "logging" | "defaults" | "plugin" | "stages" | "svparser" | "transport" => {
-                        warn_nested_unknowns(k, v);
-                    }
-                    "rule" => warn_rule_unknowns(v),
-                    other => tracing::warn!("unknown top-level key: {}", other),
+pub(super) fn warn_on_unknown_keys(table: &toml::Value) {
+    if let Some(obj) = table.as_table() {
+        for (k, v) in obj {
+            match k.as_str() {
+                "logging" | "defaults" | "plugin" | "stages" | "svparser" | "transport" | "output" | "inputs" => {
+                    warn_nested_unknowns(k, v);
                 }
+                "rule" => warn_rule_unknowns(v),
+                "override" => warn_override_unknowns(v),
+                "extends" => {}
+                other => tracing::warn!("unknown top-level key: {}", other),
             }
         }
     }
@@ -82,7 +90,15 @@ fn warn_nested_unknowns(parent: &str, val: &toml::Value) {
             "format",
         ],
         "defaults" => &["timeout_ms_per_file"],
-        "plugin" => &["cmd", "args", "root", "search_paths"],
+        "plugin" => &[
+            "cmd",
+            "args",
+            "root",
+            "search_paths",
+            "hosts",
+            "on_rule_crash",
+            "max_restarts",
+        ],
         "stages" => &["enabled", "required"],
         "svparser" => &[
             "include_paths",
@@ -98,6 +114,8 @@ fn warn_nested_unknowns(parent: &str, val: &toml::Value) {
             "on_exceed",
             "fail_ci_on_skip",
         ],
+        "output" => &["format"],
+        "inputs" => &["extensions", "include", "exclude", "gitignore"],
         _ => &[],
     };
     for key in table.keys() {
@@ -111,7 +129,9 @@ fn warn_rule_unknowns(val: &toml::Value) {
     let Some(array) = val.as_array() else { return };
     for (idx, entry) in array.iter().enumerate() {
         let Some(table) = entry.as_table() else { continue };
-        let known = ["id", "script", "stage", "enabled", "severity"];
+        let known = [
+            "id", "script", "native", "host", "stage", "enabled", "severity", "options",
+        ];
         for key in table.keys() {
             if !known.contains(&key.as_str()) {
                 tracing::warn!("unknown key rule[{}].{}", idx, key);
@@ -119,3 +139,27 @@ fn warn_rule_unknowns(val: &toml::Value) {
         }
     }
 }
+
+fn warn_override_unknowns(val: &toml::Value) {
+    let Some(array) = val.as_array() else { return };
+    for (idx, entry) in array.iter().enumerate() {
+        let Some(table) = entry.as_table() else { continue };
+        for (key, value) in table {
+            match key.as_str() {
+                "paths" => {}
+                "rule" => {
+                    let Some(rules) = value.as_array() else { continue };
+                    for (rule_idx, rule) in rules.iter().enumerate() {
+                        let Some(rule) = rule.as_table() else { continue };
+                        for rule_key in rule.keys() {
+                            if !["id", "enabled", "severity", "options"].contains(&rule_key.as_str()) {
+                                tracing::warn!("unknown key override[{}].rule[{}].{}", idx, rule_idx, rule_key);
+                            }
+                        }
+                    }
+                }
+                other => tracing::warn!("unknown key override[{}].{}", idx, other),
+            }
+        }
+    }
+}
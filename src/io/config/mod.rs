@@ -1,3 +1,6 @@
+mod builder;
+mod discover;
+mod extends;
 mod input;
 mod loader;
 mod normalize;
@@ -6,12 +9,15 @@ mod paths;
 mod types;
 mod validate;
 
-pub use input::{read_input, InputText};
+pub use builder::ConfigBuilder;
+pub use discover::{discover_config, group_by_config, CONFIG_FILE_NAME};
+pub use input::{read_input, read_stdin, InputText, STDIN_INPUT};
 pub use loader::{load, load_from_path, resolve_path};
-pub use overrides::apply_rule_overrides;
+pub use overrides::{apply_rule_overrides, restrict_overrides_to_enabled};
 pub use paths::plugin_search_paths;
 pub use types::{
-    Config, Defaults, LogFormat, LoggingConfig, Plugin, RuleConfig, Stages, TransportConfig, TransportOnExceed,
+    Config, Defaults, HostCommand, InputsConfig, LogFormat, LoggingConfig, OutputConfig, OutputFormat, OverrideConfig,
+    Plugin, RuleConfig, RuleCrashPolicy, RuleOverride, Stages, TransportConfig, TransportOnExceed,
 };
 
 #[cfg(test)]
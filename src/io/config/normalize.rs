@@ -1,11 +1,13 @@
 use super::validate::validate_plugin_dirs;
 use super::{Config, RuleConfig};
+use crate::core::native;
 use crate::errors::ConfigError;
 use crate::types::Stage;
 use std::path::{Path, PathBuf};
 
 pub(super) fn normalize_rule_scripts(cfg: &mut Config, base_dir: &Path) -> Result<(), ConfigError> {
     cfg.plugin.config_dir = Some(base_dir.to_path_buf());
+    cfg.inputs.base_dir = Some(base_dir.to_path_buf());
     cfg.plugin.normalized_root = cfg.plugin.root.as_ref().map(|root| to_abs(base_dir, root));
     cfg.plugin.normalized_search_paths = cfg.plugin.search_paths.iter().map(|p| to_abs(base_dir, p)).collect();
     let mut search_roots = Vec::new();
@@ -17,13 +19,22 @@ pub(super) fn normalize_rule_scripts(cfg: &mut Config, base_dir: &Path) -> Resul
     }
     let has_user_roots = !search_roots.is_empty();
     let fallback_root = base_dir.join("plugins");
-    let needs_default_root = cfg.rule.iter().any(|r| r.script.trim().is_empty());
+    let needs_default_root = cfg.rule.iter().any(|r| !r.native && r.script.trim().is_empty());
     validate_plugin_dirs(cfg, &fallback_root, has_user_roots, needs_default_root)?;
     if !has_user_roots {
         search_roots.push(fallback_root.clone());
     }
     for entry in &mut cfg.rule {
+        if entry.native {
+            entry.script.clear();
+            continue;
+        }
         if entry.script.trim().is_empty() {
+            if let Some(host) = &entry.host {
+                return Err(ConfigError::InvalidValue {
+                    detail: format!("rule {} runs on host {} and must set script", entry.id, host),
+                });
+            }
             entry.script = derive_script_from_id(entry, &search_roots)?;
             if !has_user_roots {
                 let absolute = fallback_root.join(entry.script.as_str());
@@ -83,6 +94,20 @@ pub(super) fn derive_script_from_id(entry: &RuleConfig, roots: &[PathBuf]) -> Re
 
 pub(super) fn infer_rule_stages(rules: &mut [RuleConfig]) -> Result<(), ConfigError> {
     for rule in rules {
+        if rule.native {
+            let native = native::builtin()
+                .get(&rule.id)
+                .ok_or_else(|| ConfigError::InvalidValue {
+                    detail: format!("rule {} is not a native rule", rule.id),
+                })?;
+            if rule.stage.is_some_and(|stage| stage != native.stage()) {
+                return Err(ConfigError::InvalidValue {
+                    detail: format!("rule {} runs at stage {}", rule.id, native.stage().as_str()),
+                });
+            }
+            rule.stage = Some(native.stage());
+            continue;
+        }
         if rule.stage.is_some() {
             continue;
         }
@@ -101,7 +126,7 @@ pub(super) fn infer_stage_from_script(rule: &RuleConfig) -> Result<Stage, Config
             detail: format!("rule {} missing stage and script {} is invalid", rule.id, rule.script),
         })?;
     let ext = path.extension().and_then(|s| s.to_str()).unwrap_or("");
-    if ext != "py" {
+    if ext != "py" && rule.host.is_none() {
         return Err(ConfigError::InvalidValue {
             detail: format!(
                 "rule {} missing stage and script {} must end with .py",
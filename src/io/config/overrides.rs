@@ -1,6 +1,9 @@
-use super::RuleConfig;
+use super::{Config, OverrideConfig, RuleConfig};
 use crate::errors::ConfigError;
+use crate::inputs::{build_globset, relative_to};
+use std::borrow::Cow;
 use std::collections::HashSet;
+use std::path::Path;
 
 pub fn apply_rule_overrides(rules: &mut [RuleConfig], only: &[String], disable: &[String]) -> Result<(), ConfigError> {
     if only.is_empty() && disable.is_empty() {
@@ -30,3 +33,60 @@ pub fn apply_rule_overrides(rules: &mut [RuleConfig], only: &[String], disable:
     }
     Ok(())
 }
+
+pub fn restrict_overrides_to_enabled(overrides: &mut [OverrideConfig], rules: &[RuleConfig]) {
+    let disabled: HashSet<&str> = rules.iter().filter(|r| !r.enabled).map(|r| r.id.as_str()).collect();
+    for entry in overrides {
+        for rule in &mut entry.rule {
+            if disabled.contains(rule.id.as_str()) {
+                rule.enabled = None;
+            }
+        }
+    }
+}
+
+impl OverrideConfig {
+    pub(super) fn compile(&mut self) -> Result<(), ConfigError> {
+        self.matcher = Some(build_globset(&self.paths, "override.paths")?);
+        Ok(())
+    }
+
+    pub fn matches(&self, path: &Path) -> bool {
+        let base_dir = match self.base_dir.as_ref() {
+            Some(dir) => dir.clone(),
+            None => std::env::current_dir().unwrap_or_default(),
+        };
+        let rel = relative_to(&base_dir, path);
+        match self.matcher.as_ref() {
+            Some(matcher) => matcher.is_match(&rel),
+            None => build_globset(&self.paths, "override.paths").is_ok_and(|m| m.is_match(&rel)),
+        }
+    }
+}
+
+impl Config {
+    pub fn rules_for(&self, path: &Path) -> Cow<'_, [RuleConfig]> {
+        let mut matching = self.overrides.iter().filter(|o| o.matches(path)).peekable();
+        if matching.peek().is_none() {
+            return Cow::Borrowed(&self.rule);
+        }
+        let mut rules = self.rule.clone();
+        for entry in matching {
+            for ov in &entry.rule {
+                let Some(rule) = rules.iter_mut().find(|r| r.id == ov.id) else {
+                    continue;
+                };
+                if let Some(enabled) = ov.enabled {
+                    rule.enabled = enabled;
+                }
+                if let Some(severity) = ov.severity.as_ref() {
+                    rule.severity = Some(severity.clone());
+                }
+                for (key, value) in &ov.options {
+                    rule.options.insert(key.clone(), value.clone());
+                }
+            }
+        }
+        Cow::Owned(rules)
+    }
+}
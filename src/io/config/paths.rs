@@ -19,9 +19,10 @@ pub fn plugin_search_paths(cfg: &Config, rel: &str) -> Vec<PathBuf> {
     for extra in &cfg.plugin.normalized_search_paths {
         push_unique(&mut out, &mut seen, extra.join(rel));
     }
-    if !has_user_roots && cfg.plugin.config_dir.is_some() {
-        let config_dir = cfg.plugin.config_dir.as_ref().unwrap();
-        push_unique(&mut out, &mut seen, config_dir.join("plugins").join(rel));
+    if !has_user_roots {
+        if let Some(config_dir) = cfg.plugin.config_dir.as_ref() {
+            push_unique(&mut out, &mut seen, config_dir.join("plugins").join(rel));
+        }
     }
     if !out.is_empty() {
         return out;
@@ -13,23 +13,32 @@ fn make_rules() -> Vec<RuleConfig> {
         RuleConfig {
             id: "a".to_string(),
             script: "a.py".to_string(),
+            native: false,
+            host: None,
             stage: Some(Stage::RawText),
             enabled: true,
             severity: None,
+            options: Default::default(),
         },
         RuleConfig {
             id: "b".to_string(),
             script: "b.py".to_string(),
+            native: false,
+            host: None,
             stage: Some(Stage::RawText),
             enabled: true,
             severity: None,
+            options: Default::default(),
         },
         RuleConfig {
             id: "c".to_string(),
             script: "c.py".to_string(),
+            native: false,
+            host: None,
             stage: Some(Stage::RawText),
             enabled: true,
             severity: None,
+            options: Default::default(),
         },
     ]
 }
@@ -82,9 +91,12 @@ fn infers_stage_from_script_suffix() {
     let mut rules = vec![RuleConfig {
         id: "flow.wait_macro_required".to_string(),
         script: "plugins/flow.wait_macro_required.raw.py".to_string(),
+        native: false,
+        host: None,
         stage: None,
         enabled: true,
         severity: None,
+        options: Default::default(),
     }];
     infer_rule_stages(&mut rules).unwrap();
     assert!(matches!(rules[0].stage(), Stage::RawText));
@@ -95,14 +107,60 @@ fn errors_when_stage_suffix_missing() {
     let mut rules = vec![RuleConfig {
         id: "example".to_string(),
         script: "plugins/example.py".to_string(),
+        native: false,
+        host: None,
         stage: None,
         enabled: true,
         severity: None,
+        options: Default::default(),
     }];
     let err = infer_rule_stages(&mut rules);
     assert!(matches!(err, Err(ConfigError::InvalidValue { .. })));
 }
 
+#[test]
+fn native_rules_take_stage_from_registry() {
+    let mut rules = vec![RuleConfig {
+        id: "module_names_lower_snake".to_string(),
+        script: String::new(),
+        native: true,
+        host: None,
+        stage: None,
+        enabled: true,
+        severity: None,
+        options: Default::default(),
+    }];
+    infer_rule_stages(&mut rules).unwrap();
+    assert!(matches!(rules[0].stage(), Stage::Ast));
+
+    rules[0].id = "no_such_native_rule".to_string();
+    rules[0].stage = None;
+    let err = infer_rule_stages(&mut rules).unwrap_err();
+    assert!(err.to_string().contains("is not a native rule"));
+}
+
+#[test]
+fn rule_host_must_be_declared() {
+    let mut cfg = load(
+        r#"
+[plugin.hosts.node]
+cmd = "node"
+args = ["host.js"]
+
+[[rule]]
+id = "js_rule"
+host = "nodejs"
+script = "js_rule.raw.js"
+"#,
+    )
+    .expect("load");
+    assert_eq!(cfg.plugin.hosts["node"].args, vec!["host.js"]);
+    infer_rule_stages(&mut cfg.rule).expect("stage from any extension");
+    assert!(matches!(cfg.rule[0].stage(), Stage::RawText));
+    let err = validate_rule_script_paths(&cfg).unwrap_err();
+    assert!(err.to_string().contains("host nodejs is not defined"));
+}
+
 #[test]
 fn config_sections_use_defaults() {
     let cfg = load(
@@ -372,7 +430,7 @@ unknown_transport = true
 foo = "bar"
 "#;
     let _ = load(cfg_text).expect("load");
-    warn_on_unknown_keys(cfg_text);
+    warn_on_unknown_keys(&cfg_text.parse().expect("toml"));
 }
 
 #[test]
@@ -386,7 +444,7 @@ unknown_field = true
 "#;
     let cfg = load(cfg_text).expect("load");
     assert_eq!(cfg.rule.len(), 1);
-    warn_on_unknown_keys(cfg_text);
+    warn_on_unknown_keys(&cfg_text.parse().expect("toml"));
 }
 
 #[test]
@@ -559,3 +617,57 @@ id = "dv_dpi_import_prefix"
     let err = normalize_rule_scripts(&mut cfg, tmp.path()).unwrap_err();
     assert!(matches!(err, ConfigError::InvalidValue { .. }));
 }
+
+#[test]
+fn output_format_parses_and_defaults_to_text() {
+    let cfg = load("").expect("load");
+    assert_eq!(cfg.output.format, OutputFormat::Text);
+    let cfg = load(
+        r#"
+[output]
+format = "ndjson"
+"#,
+    )
+    .expect("load");
+    assert_eq!(cfg.output.format, OutputFormat::Ndjson);
+    assert!("sarif-ish".parse::<OutputFormat>().is_err());
+}
+
+#[test]
+fn inputs_table_defaults_and_overrides() {
+    let cfg = load("").expect("load");
+    assert_eq!(cfg.inputs.extensions, vec!["sv", "svh", "v", "vh"]);
+    assert!(cfg.inputs.gitignore);
+    let cfg = load(
+        r#"
+[inputs]
+extensions = ["sv"]
+exclude = ["rtl/gen/**"]
+gitignore = false
+"#,
+    )
+    .expect("load");
+    assert_eq!(cfg.inputs.extensions, vec!["sv"]);
+    assert_eq!(cfg.inputs.exclude, vec!["rtl/gen/**"]);
+    assert!(!cfg.inputs.gitignore);
+}
+
+#[test]
+fn rule_options_convert_to_json() {
+    let cfg = load(
+        r#"
+[[rule]]
+id = "port_names_have_direction_suffix"
+
+[rule.options]
+max = 3
+suffixes = { input = ["_i", "_ni"] }
+"#,
+    )
+    .expect("load");
+    let options = serde_json::Value::Object(cfg.rule[0].options.clone());
+    assert_eq!(
+        options,
+        serde_json::json!({"max": 3, "suffixes": {"input": ["_i", "_ni"]}})
+    );
+}
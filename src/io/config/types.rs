@@ -1,8 +1,12 @@
+use crate::errors::ConfigError;
 use crate::svparser::SvParserCfg;
 use crate::types::Stage;
+use globset::GlobSet;
 use serde::Deserialize;
-use std::collections::HashMap;
+use serde_json::{Map as JsonMap, Value as JsonValue};
+use std::collections::{BTreeMap, HashMap};
 use std::path::PathBuf;
+use std::str::FromStr;
 use toml::Value as TomlValue;
 
 #[derive(Deserialize, Clone)]
@@ -45,7 +49,85 @@ pub enum LogFormat {
     Json,
 }
 
-#[derive(Deserialize, Clone, Copy, Default)]
+#[derive(Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+    Ndjson,
+    Sarif,
+    Junit,
+    Checkstyle,
+}
+
+impl OutputFormat {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OutputFormat::Text => "text",
+            OutputFormat::Json => "json",
+            OutputFormat::Ndjson => "ndjson",
+            OutputFormat::Sarif => "sarif",
+            OutputFormat::Junit => "junit",
+            OutputFormat::Checkstyle => "checkstyle",
+        }
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = ConfigError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            "ndjson" => Ok(OutputFormat::Ndjson),
+            "sarif" => Ok(OutputFormat::Sarif),
+            "junit" => Ok(OutputFormat::Junit),
+            "checkstyle" => Ok(OutputFormat::Checkstyle),
+            other => Err(ConfigError::InvalidValue {
+                detail: format!(
+                    "output format {} must be text|json|ndjson|sarif|junit|checkstyle",
+                    other
+                ),
+            }),
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Default)]
+pub struct OutputConfig {
+    #[serde(default)]
+    pub format: OutputFormat,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct InputsConfig {
+    #[serde(default = "default_input_extensions")]
+    pub extensions: Vec<String>,
+    #[serde(default)]
+    pub include: Vec<String>,
+    #[serde(default)]
+    pub exclude: Vec<String>,
+    #[serde(default = "default_true")]
+    pub gitignore: bool,
+    #[serde(skip)]
+    pub base_dir: Option<PathBuf>,
+}
+
+impl Default for InputsConfig {
+    fn default() -> Self {
+        Self {
+            extensions: default_input_extensions(),
+            include: Vec::new(),
+            exclude: Vec::new(),
+            gitignore: default_true(),
+            base_dir: None,
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Copy, Default, Debug)]
 #[serde(rename_all = "lowercase")]
 pub enum TransportOnExceed {
     #[default]
@@ -53,6 +135,14 @@ pub enum TransportOnExceed {
     Error,
 }
 
+#[derive(Deserialize, Clone, Copy, Default, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum RuleCrashPolicy {
+    #[default]
+    Error,
+    Warning,
+}
+
 #[derive(Deserialize, Clone)]
 pub struct TransportConfig {
     #[serde(default = "default_max_request_bytes")]
@@ -79,7 +169,7 @@ impl Default for TransportConfig {
     }
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Default)]
 pub struct Config {
     #[serde(default)]
     pub logging: LoggingConfig,
@@ -95,6 +185,12 @@ pub struct Config {
     pub rule: Vec<RuleConfig>,
     #[serde(default)]
     pub transport: TransportConfig,
+    #[serde(default)]
+    pub output: OutputConfig,
+    #[serde(default)]
+    pub inputs: InputsConfig,
+    #[serde(default, rename = "override")]
+    pub overrides: Vec<OverrideConfig>,
 }
 
 #[derive(Deserialize)]
@@ -121,6 +217,12 @@ pub struct Plugin {
     pub root: Option<String>,
     #[serde(default)]
     pub search_paths: Vec<String>,
+    #[serde(default)]
+    pub hosts: BTreeMap<String, HostCommand>,
+    #[serde(default)]
+    pub on_rule_crash: RuleCrashPolicy,
+    #[serde(default = "default_max_restarts")]
+    pub max_restarts: u32,
     #[serde(skip)]
     pub normalized_root: Option<PathBuf>,
     #[serde(skip)]
@@ -129,6 +231,13 @@ pub struct Plugin {
     pub config_dir: Option<PathBuf>,
 }
 
+#[derive(Deserialize, Clone, Debug)]
+pub struct HostCommand {
+    pub cmd: String,
+    #[serde(default)]
+    pub args: Vec<String>,
+}
+
 impl Default for Plugin {
     fn default() -> Self {
         Self {
@@ -136,6 +245,9 @@ impl Default for Plugin {
             args: default_plugin_args(),
             root: None,
             search_paths: Vec::new(),
+            hosts: BTreeMap::new(),
+            on_rule_crash: RuleCrashPolicy::Error,
+            max_restarts: default_max_restarts(),
             normalized_root: None,
             normalized_search_paths: Vec::new(),
             config_dir: None,
@@ -166,11 +278,39 @@ pub struct RuleConfig {
     #[serde(default)]
     pub script: String,
     #[serde(default)]
+    pub native: bool,
+    #[serde(default)]
+    pub host: Option<String>,
+    #[serde(default)]
     pub stage: Option<Stage>,
     #[serde(default = "default_true")]
     pub enabled: bool,
     #[serde(default)]
     pub severity: Option<String>,
+    #[serde(default)]
+    pub options: JsonMap<String, JsonValue>,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct RuleOverride {
+    pub id: String,
+    #[serde(default)]
+    pub enabled: Option<bool>,
+    #[serde(default)]
+    pub severity: Option<String>,
+    #[serde(default)]
+    pub options: JsonMap<String, JsonValue>,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct OverrideConfig {
+    pub paths: Vec<String>,
+    #[serde(default)]
+    pub rule: Vec<RuleOverride>,
+    #[serde(skip)]
+    pub base_dir: Option<PathBuf>,
+    #[serde(skip)]
+    pub(super) matcher: Option<GlobSet>,
 }
 
 impl RuleConfig {
@@ -183,10 +323,18 @@ fn default_true() -> bool {
     true
 }
 
+fn default_input_extensions() -> Vec<String> {
+    ["sv", "svh", "v", "vh"].iter().map(|s| s.to_string()).collect()
+}
+
 fn default_timeout_ms() -> u64 {
     6000
 }
 
+fn default_max_restarts() -> u32 {
+    3
+}
+
 fn default_max_request_bytes() -> usize {
     16_777_216
 }
@@ -1,6 +1,7 @@
 use super::paths::plugin_search_paths;
 use super::Config;
 use crate::errors::ConfigError;
+use crate::inputs::InputFilter;
 use crate::types::Severity;
 use std::collections::HashSet;
 use std::fs;
@@ -60,6 +61,27 @@ pub(super) fn validate_config(cfg: &Config) -> Result<(), ConfigError> {
             detail: "transport warn_margin_bytes exceeds max_request_bytes".to_string(),
         });
     }
+    InputFilter::new(&cfg.inputs)?;
+    let rule_ids: HashSet<&str> = cfg.rule.iter().map(|r| r.id.as_str()).collect();
+    for entry in &cfg.overrides {
+        if entry.paths.is_empty() {
+            return Err(ConfigError::InvalidValue {
+                detail: "override paths cannot be empty".to_string(),
+            });
+        }
+        for rule in &entry.rule {
+            if !rule_ids.contains(rule.id.as_str()) {
+                return Err(ConfigError::InvalidValue {
+                    detail: format!("override references unknown rule {}", rule.id),
+                });
+            }
+            if rule.severity.as_deref().is_some_and(|s| severity_from_str(s).is_none()) {
+                return Err(ConfigError::InvalidValue {
+                    detail: format!("override for rule {} severity must be error|warning|info", rule.id),
+                });
+            }
+        }
+    }
     Ok(())
 }
 
@@ -95,10 +117,6 @@ fn validate_dir(path: &Path, label: &str) -> Result<(), ConfigError> {
 
 pub(super) fn validate_rule_script_paths(cfg: &Config) -> Result<(), ConfigError> {
     for rule in &cfg.rule {
-        let script = rule.script.trim();
-        if script.is_empty() {
-            continue;
-        }
         if let Some(sev) = &rule.severity {
             if severity_from_str(sev).is_none() {
                 return Err(ConfigError::InvalidValue {
@@ -106,6 +124,17 @@ pub(super) fn validate_rule_script_paths(cfg: &Config) -> Result<(), ConfigError
                 });
             }
         }
+        if let Some(host) = rule.host.as_deref().filter(|_| !rule.native) {
+            if !cfg.plugin.hosts.contains_key(host) {
+                return Err(ConfigError::InvalidValue {
+                    detail: format!("rule {} host {} is not defined under plugin.hosts", rule.id, host),
+                });
+            }
+        }
+        let script = rule.script.trim();
+        if rule.native || script.is_empty() {
+            continue;
+        }
         let path = Path::new(script);
         let mut probed = if path.is_absolute() {
             vec![path.to_path_buf()]
@@ -0,0 +1,267 @@
+use crate::errors::ConfigError;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const MAX_NESTING: usize = 32;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RelativeTo {
+    Cwd,
+    ListDir,
+}
+
+#[derive(Default, Debug)]
+pub struct FileList {
+    pub inputs: Vec<PathBuf>,
+    pub include_paths: Vec<String>,
+    pub defines: Vec<String>,
+    pub library_dirs: Vec<PathBuf>,
+    pub library_files: Vec<PathBuf>,
+}
+
+impl FileList {
+    pub fn load(path: &Path, relative_to: RelativeTo) -> Result<Self, ConfigError> {
+        let mut list = FileList::default();
+        let mut stack = Vec::new();
+        list.read(path, relative_to, &mut stack)?;
+        Ok(list)
+    }
+
+    pub fn extend(&mut self, other: FileList) {
+        self.inputs.extend(other.inputs);
+        self.include_paths.extend(other.include_paths);
+        self.defines.extend(other.defines);
+        self.library_dirs.extend(other.library_dirs);
+        self.library_files.extend(other.library_files);
+    }
+
+    fn read(&mut self, path: &Path, relative_to: RelativeTo, stack: &mut Vec<PathBuf>) -> Result<(), ConfigError> {
+        let key = fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+        if stack.contains(&key) {
+            return Err(ConfigError::InvalidValue {
+                detail: format!("file list {} includes itself", path.display()),
+            });
+        }
+        if stack.len() >= MAX_NESTING {
+            return Err(ConfigError::InvalidValue {
+                detail: format!("file list {} nested too deeply", path.display()),
+            });
+        }
+        let text = fs::read_to_string(path).map_err(|e| ConfigError::IoFailed {
+            detail: format!("{} ({})", path.display(), e),
+        })?;
+        let base = match relative_to {
+            RelativeTo::Cwd => PathBuf::new(),
+            RelativeTo::ListDir => path.parent().map(Path::to_path_buf).unwrap_or_default(),
+        };
+        let resolve = |p: &str| -> PathBuf {
+            let p = Path::new(p);
+            if p.is_absolute() {
+                p.to_path_buf()
+            } else {
+                base.join(p)
+            }
+        };
+        stack.push(key);
+        let mut tokens = tokenize(&text).into_iter();
+        while let Some(raw) = tokens.next() {
+            let token = expand_env(&raw, env_var).map_err(|name| ConfigError::InvalidValue {
+                detail: format!("{}: undefined environment variable {}", path.display(), name),
+            })?;
+            let mut operand = |opt: &str| -> Result<String, ConfigError> {
+                let next = tokens.next().ok_or_else(|| ConfigError::InvalidValue {
+                    detail: format!("{}: {} expects an argument", path.display(), opt),
+                })?;
+                expand_env(&next, env_var).map_err(|name| ConfigError::InvalidValue {
+                    detail: format!("{}: undefined environment variable {}", path.display(), name),
+                })
+            };
+            match token.as_str() {
+                "-f" => {
+                    let nested = resolve(&operand("-f")?);
+                    self.read(&nested, RelativeTo::Cwd, stack)?;
+                }
+                "-F" => {
+                    let nested = resolve(&operand("-F")?);
+                    self.read(&nested, RelativeTo::ListDir, stack)?;
+                }
+                "-y" => {
+                    let dir = resolve(&operand("-y")?);
+                    self.library_dirs.push(dir);
+                }
+                "-v" => {
+                    let file = resolve(&operand("-v")?);
+                    self.library_files.push(file);
+                }
+                _ => {
+                    if let Some(rest) = token.strip_prefix("+incdir+") {
+                        for dir in rest.split('+').filter(|s| !s.is_empty()) {
+                            self.include_paths.push(resolve(dir).to_string_lossy().into_owned());
+                        }
+                    } else if let Some(rest) = token.strip_prefix("+define+") {
+                        self.defines
+                            .extend(rest.split('+').filter(|s| !s.is_empty()).map(str::to_string));
+                    } else if token.starts_with('+') || token.starts_with('-') {
+                        tracing::warn!("{}: ignoring file list option {}", path.display(), token);
+                    } else {
+                        self.inputs.push(resolve(&token));
+                    }
+                }
+            }
+        }
+        stack.pop();
+        Ok(())
+    }
+}
+
+fn tokenize(text: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut cur = String::new();
+    let mut chars = text.chars().peekable();
+    let mut at_line_start = true;
+    while let Some(c) = chars.next() {
+        match c {
+            '#' if at_line_start && cur.is_empty() => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        break;
+                    }
+                }
+                continue;
+            }
+            '/' if cur.is_empty() && chars.peek() == Some(&'/') => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        break;
+                    }
+                }
+                at_line_start = true;
+                continue;
+            }
+            '/' if cur.is_empty() && chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut prev = '\0';
+                for c in chars.by_ref() {
+                    if prev == '*' && c == '/' {
+                        break;
+                    }
+                    prev = c;
+                }
+                continue;
+            }
+            '"' | '\'' => {
+                for q in chars.by_ref() {
+                    if q == c {
+                        break;
+                    }
+                    cur.push(q);
+                }
+            }
+            c if c.is_whitespace() => {
+                if !cur.is_empty() {
+                    tokens.push(std::mem::take(&mut cur));
+                }
+                if c == '\n' {
+                    at_line_start = true;
+                }
+                continue;
+            }
+            c => cur.push(c),
+        }
+        at_line_start = false;
+    }
+    if !cur.is_empty() {
+        tokens.push(cur);
+    }
+    tokens
+}
+
+fn env_var(name: &str) -> Option<String> {
+    env::var(name).ok()
+}
+
+fn expand_env(token: &str, lookup: impl Fn(&str) -> Option<String>) -> Result<String, String> {
+    let mut out = String::with_capacity(token.len());
+    let mut rest = token;
+    while let Some(pos) = rest.find('$') {
+        out.push_str(&rest[..pos]);
+        rest = &rest[pos + 1..];
+        let (name, tail) = match rest.chars().next() {
+            Some(open @ ('{' | '(')) => {
+                let close = if open == '{' { '}' } else { ')' };
+                match rest.find(close) {
+                    Some(end) => (&rest[1..end], &rest[end + 1..]),
+                    None => return Err(rest.to_string()),
+                }
+            }
+            _ => {
+                let end = rest
+                    .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                    .unwrap_or(rest.len());
+                (&rest[..end], &rest[end..])
+            }
+        };
+        if name.is_empty() {
+            out.push('$');
+        } else {
+            out.push_str(&lookup(name).ok_or_else(|| name.to_string())?);
+        }
+        rest = tail;
+    }
+    out.push_str(rest);
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use tempfile::tempdir;
+
+    #[test]
+    fn tokenizes_comments_and_quotes() {
+        let text = "# header\n// note\na.sv /* inline */ \"b c.sv\"\n+define+X=1 // tail\n";
+        assert_eq!(tokenize(text), vec!["a.sv", "b c.sv", "+define+X=1"]);
+    }
+
+    #[test]
+    fn expands_environment_variables() {
+        let vars = HashMap::from([("PROJ", "/proj")]);
+        let lookup = |name: &str| vars.get(name).map(|v| v.to_string());
+        assert_eq!(
+            expand_env("$PROJ/rtl/${PROJ}/$(PROJ)", lookup).unwrap(),
+            "/proj/rtl//proj//proj"
+        );
+        assert_eq!(expand_env("$MISSING", lookup).unwrap_err(), "MISSING");
+    }
+
+    #[test]
+    fn loads_nested_lists_with_options() {
+        let tmp = tempdir().unwrap();
+        let root = tmp.path();
+        fs::create_dir_all(root.join("sub")).unwrap();
+        fs::write(
+            root.join("top.f"),
+            "+incdir+inc+inc2\n+define+A=1+B\n-y lib\n-v cells.v\n+libext+.v\ntop.sv\n-F sub/sub.f\n",
+        )
+        .unwrap();
+        fs::write(root.join("sub/sub.f"), "leaf.sv\n-F ../top.f\n").unwrap();
+        let err = FileList::load(&root.join("top.f"), RelativeTo::ListDir).unwrap_err();
+        assert!(err.to_string().contains("includes itself"));
+
+        fs::write(root.join("sub/sub.f"), "leaf.sv\n").unwrap();
+        let list = FileList::load(&root.join("top.f"), RelativeTo::ListDir).unwrap();
+        assert_eq!(list.inputs, vec![root.join("top.sv"), root.join("sub").join("leaf.sv")]);
+        assert_eq!(
+            list.include_paths,
+            vec![
+                root.join("inc").to_string_lossy().into_owned(),
+                root.join("inc2").to_string_lossy().into_owned()
+            ]
+        );
+        assert_eq!(list.defines, vec!["A=1", "B"]);
+        assert_eq!(list.library_dirs, vec![root.join("lib")]);
+        assert_eq!(list.library_files, vec![root.join("cells.v")]);
+    }
+}
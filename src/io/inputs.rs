@@ -0,0 +1,280 @@
+use crate::config::InputsConfig;
+use crate::errors::ConfigError;
+use globset::{Glob, GlobBuilder, GlobMatcher, GlobSet, GlobSetBuilder};
+use ignore::WalkBuilder;
+use std::collections::HashSet;
+use std::env;
+use std::path::{Path, PathBuf};
+
+pub struct InputFilter {
+    extensions: Vec<String>,
+    include: Option<GlobSet>,
+    exclude: GlobSet,
+    gitignore: bool,
+    base_dir: PathBuf,
+}
+
+impl InputFilter {
+    pub fn new(cfg: &InputsConfig) -> Result<Self, ConfigError> {
+        let cwd = env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
+        let base_dir = match cfg.base_dir.as_ref() {
+            Some(dir) if dir.is_absolute() => dir.clone(),
+            Some(dir) => cwd.join(dir),
+            None => cwd,
+        };
+        let include = if cfg.include.is_empty() {
+            None
+        } else {
+            Some(build_globset(&cfg.include, "inputs.include")?)
+        };
+        Ok(Self {
+            extensions: cfg
+                .extensions
+                .iter()
+                .map(|e| e.trim_start_matches('.').to_ascii_lowercase())
+                .collect(),
+            include,
+            exclude: build_globset(&cfg.exclude, "inputs.exclude")?,
+            gitignore: cfg.gitignore,
+            base_dir,
+        })
+    }
+
+    fn has_extension(&self, path: &Path) -> bool {
+        let Some(ext) = path.extension().and_then(|e| e.to_str()) else {
+            return false;
+        };
+        let ext = ext.to_ascii_lowercase();
+        self.extensions.contains(&ext)
+    }
+
+    fn relative(&self, path: &Path) -> PathBuf {
+        relative_to(&self.base_dir, path)
+    }
+
+    fn is_excluded(&self, path: &Path) -> bool {
+        excluded(&self.exclude, &self.base_dir, path)
+    }
+
+    pub fn selects(&self, path: &Path) -> bool {
+        if !self.has_extension(path) || self.is_excluded(path) {
+            return false;
+        }
+        match self.include.as_ref() {
+            Some(include) => include.is_match(self.relative(path)),
+            None => true,
+        }
+    }
+
+    fn walk(&self, root: &Path, mut visit: impl FnMut(&Path)) -> Result<(), ConfigError> {
+        let mut builder = WalkBuilder::new(root);
+        builder
+            .ignore(false)
+            .git_ignore(self.gitignore)
+            .git_global(self.gitignore)
+            .git_exclude(self.gitignore)
+            .require_git(false)
+            .sort_by_file_name(|a, b| a.cmp(b));
+        let exclude = self.exclude.clone();
+        let base_dir = self.base_dir.clone();
+        builder.filter_entry(move |entry| entry.depth() == 0 || !excluded(&exclude, &base_dir, entry.path()));
+        for entry in builder.build() {
+            let entry = entry.map_err(|e| ConfigError::IoFailed {
+                detail: format!("{} ({})", root.display(), e),
+            })?;
+            if entry.file_type().is_some_and(|t| t.is_file()) {
+                visit(entry.path());
+            }
+        }
+        Ok(())
+    }
+}
+
+pub(crate) fn relative_to(base_dir: &Path, path: &Path) -> PathBuf {
+    let abs = if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        env::current_dir()
+            .map(|cwd| cwd.join(path))
+            .unwrap_or_else(|_| path.to_path_buf())
+    };
+    match abs.strip_prefix(base_dir) {
+        Ok(rel) => rel.to_path_buf(),
+        Err(_) => path.to_path_buf(),
+    }
+}
+
+fn excluded(exclude: &GlobSet, base_dir: &Path, path: &Path) -> bool {
+    !exclude.is_empty() && exclude.is_match(relative_to(base_dir, path))
+}
+
+pub fn expand_inputs(cfg: &InputsConfig, args: &[PathBuf]) -> Result<Vec<PathBuf>, ConfigError> {
+    if args.is_empty() {
+        return Err(ConfigError::InvalidValue {
+            detail: "no input files provided".to_string(),
+        });
+    }
+    let filter = InputFilter::new(cfg)?;
+    let mut seen = HashSet::new();
+    let mut out = Vec::new();
+    let mut push = |path: PathBuf| {
+        if seen.insert(path.clone()) {
+            out.push(path);
+        }
+    };
+    for arg in args {
+        if arg.is_dir() {
+            filter.walk(arg, |path| {
+                if filter.selects(path) {
+                    push(path.to_path_buf());
+                }
+            })?;
+        } else if !arg.exists() && is_glob(&arg.to_string_lossy()) {
+            let pattern = arg.to_string_lossy().replace('\\', "/");
+            let matcher = compile_glob(&pattern, "input")?;
+            let root = glob_root(&pattern);
+            let mut matched = false;
+            let walk_root = if root.as_os_str().is_empty() {
+                Path::new(".")
+            } else {
+                &root
+            };
+            filter.walk(walk_root, |path| {
+                let path = path.strip_prefix("./").unwrap_or(path);
+                if matcher.is_match(path) && !filter.is_excluded(path) {
+                    matched = true;
+                    push(path.to_path_buf());
+                }
+            })?;
+            if !matched {
+                return Err(ConfigError::InvalidValue {
+                    detail: format!("no input files match {}", pattern),
+                });
+            }
+        } else {
+            push(arg.clone());
+        }
+    }
+    if out.is_empty() {
+        return Err(ConfigError::InvalidValue {
+            detail: "no input files found".to_string(),
+        });
+    }
+    Ok(out)
+}
+
+fn is_glob(s: &str) -> bool {
+    s.contains(['*', '?', '[', '{'])
+}
+
+fn glob_root(pattern: &str) -> PathBuf {
+    let mut root = PathBuf::new();
+    let mut parts = pattern.split('/').peekable();
+    while let Some(part) = parts.next() {
+        if is_glob(part) || parts.peek().is_none() {
+            break;
+        }
+        if part.is_empty() && root.as_os_str().is_empty() {
+            root.push("/");
+        } else {
+            root.push(part);
+        }
+    }
+    root
+}
+
+fn compile_glob(pattern: &str, label: &str) -> Result<GlobMatcher, ConfigError> {
+    GlobBuilder::new(pattern)
+        .literal_separator(true)
+        .build()
+        .map(|g| g.compile_matcher())
+        .map_err(|e| ConfigError::InvalidValue {
+            detail: format!("{} glob {}: {}", label, pattern, e),
+        })
+}
+
+pub(crate) fn build_globset(patterns: &[String], label: &str) -> Result<GlobSet, ConfigError> {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        let glob = Glob::new(pattern.trim_end_matches('/')).map_err(|e| ConfigError::InvalidValue {
+            detail: format!("{} glob {}: {}", label, pattern, e),
+        })?;
+        builder.add(glob);
+    }
+    builder.build().map_err(|e| ConfigError::InvalidValue {
+        detail: format!("{}: {}", label, e),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::tempdir;
+
+    fn touch(path: &Path) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, "module m; endmodule\n").unwrap();
+    }
+
+    #[test]
+    fn walks_directories_with_extensions_and_excludes() {
+        let tmp = tempdir().unwrap();
+        let root = tmp.path();
+        touch(&root.join("rtl/a.sv"));
+        touch(&root.join("rtl/pkg.SVH"));
+        touch(&root.join("rtl/notes.txt"));
+        touch(&root.join("rtl/gen/b.sv"));
+        touch(&root.join("rtl/ignored/c.sv"));
+        fs::write(root.join("rtl/.gitignore"), "ignored/\n").unwrap();
+        let cfg = InputsConfig {
+            exclude: vec!["rtl/gen".to_string()],
+            base_dir: Some(root.to_path_buf()),
+            ..InputsConfig::default()
+        };
+        let got = expand_inputs(&cfg, &[root.join("rtl")]).unwrap();
+        assert_eq!(got, vec![root.join("rtl/a.sv"), root.join("rtl/pkg.SVH")]);
+
+        let cfg = InputsConfig {
+            gitignore: false,
+            include: vec!["**/c.sv".to_string()],
+            base_dir: Some(root.to_path_buf()),
+            ..InputsConfig::default()
+        };
+        let got = expand_inputs(&cfg, &[root.join("rtl")]).unwrap();
+        assert_eq!(got, vec![root.join("rtl/ignored/c.sv")]);
+    }
+
+    #[test]
+    fn expands_glob_patterns() {
+        let tmp = tempdir().unwrap();
+        let root = tmp.path();
+        touch(&root.join("rtl/a.sv"));
+        touch(&root.join("rtl/sub/b.sv"));
+        touch(&root.join("rtl/sub/c.v"));
+        let pattern = format!("{}/rtl/**/*.sv", root.to_string_lossy().replace('\\', "/"));
+        let got = expand_inputs(&InputsConfig::default(), &[PathBuf::from(&pattern)]).unwrap();
+        assert_eq!(got.len(), 2);
+        assert!(got.iter().all(|p| p.extension().unwrap() == "sv"));
+
+        let missing = format!("{}/rtl/*.vhd", root.to_string_lossy().replace('\\', "/"));
+        assert!(expand_inputs(&InputsConfig::default(), &[PathBuf::from(missing)]).is_err());
+    }
+
+    #[test]
+    fn explicit_files_are_kept_as_given() {
+        let cfg = InputsConfig {
+            exclude: vec!["**".to_string()],
+            ..InputsConfig::default()
+        };
+        let got = expand_inputs(&cfg, &[PathBuf::from("missing.sv"), PathBuf::from("missing.sv")]).unwrap();
+        assert_eq!(got, vec![PathBuf::from("missing.sv")]);
+    }
+
+    #[test]
+    fn glob_root_stops_at_first_pattern() {
+        assert_eq!(glob_root("rtl/**/*.sv"), PathBuf::from("rtl"));
+        assert_eq!(glob_root("*.sv"), PathBuf::new());
+        assert_eq!(glob_root("/abs/x/*.sv"), PathBuf::from("/abs/x"));
+    }
+}
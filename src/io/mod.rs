@@ -1,3 +1,5 @@
 pub mod config;
+pub mod filelist;
+pub mod inputs;
 pub mod output;
 pub mod textutil;
@@ -0,0 +1,92 @@
+use super::{severity_name, with_file, xml_escape, Reporter};
+use crate::core::pipeline::RunSummary;
+use crate::types::Violation;
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+use std::path::Path;
+
+pub struct CheckstyleReporter<W: Write + Send> {
+    out: W,
+    files: BTreeMap<String, Vec<Violation>>,
+}
+
+impl<W: Write + Send> CheckstyleReporter<W> {
+    pub fn new(out: W) -> Self {
+        Self {
+            out,
+            files: BTreeMap::new(),
+        }
+    }
+}
+
+impl<W: Write + Send> Reporter for CheckstyleReporter<W> {
+    fn report_file(&mut self, input_path: &Path, violations: &[Violation]) -> io::Result<()> {
+        self.files.entry(input_path.to_string_lossy().into_owned()).or_default();
+        for v in violations {
+            let v = with_file(v, input_path);
+            let file = v.location.file.clone().unwrap_or_default();
+            self.files.entry(file).or_default().push(v);
+        }
+        Ok(())
+    }
+
+    fn finish(&mut self, _summary: &RunSummary) -> io::Result<()> {
+        writeln!(self.out, "<?xml version=\"1.0\" encoding=\"UTF-8\"?>")?;
+        writeln!(self.out, "<checkstyle version=\"4.3\">")?;
+        for (path, violations) in &self.files {
+            writeln!(self.out, "  <file name=\"{}\">", xml_escape(path))?;
+            for v in violations {
+                writeln!(
+                    self.out,
+                    "    <error line=\"{}\" column=\"{}\" severity=\"{}\" message=\"{}\" source=\"sv-mint.{}\"/>",
+                    v.location.line.max(1),
+                    v.location.col.max(1),
+                    severity_name(v.severity),
+                    xml_escape(&v.message),
+                    xml_escape(&v.rule_id)
+                )?;
+            }
+            writeln!(self.out, "  </file>")?;
+        }
+        writeln!(self.out, "</checkstyle>")?;
+        self.out.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Location, Severity};
+
+    #[test]
+    fn groups_errors_by_violation_file() {
+        let mut rep = CheckstyleReporter::new(Vec::new());
+        let v = |file: Option<&str>| Violation {
+            rule_id: "r".to_string(),
+            severity: Severity::Error,
+            message: "bad \"quote\"".to_string(),
+            location: Location {
+                line: 7,
+                col: 1,
+                end_line: 7,
+                end_col: 2,
+                file: file.map(str::to_string),
+            },
+            fixes: Vec::new(),
+        };
+        rep.report_file(Path::new("top.sv"), &[v(None), v(Some("inc.svh"))])
+            .unwrap();
+        rep.report_file(Path::new("clean.sv"), &[]).unwrap();
+        rep.finish(&RunSummary {
+            violations: 2,
+            had_error: false,
+        })
+        .unwrap();
+        let xml = String::from_utf8(rep.out).unwrap();
+        assert!(xml.contains("<file name=\"clean.sv\">\n  </file>"));
+        assert!(xml.contains("<file name=\"inc.svh\">"));
+        assert!(xml.contains(
+            "<error line=\"7\" column=\"1\" severity=\"error\" message=\"bad &quot;quote&quot;\" source=\"sv-mint.r\"/>"
+        ));
+    }
+}
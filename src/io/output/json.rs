@@ -0,0 +1,183 @@
+use super::{with_file, Reporter};
+use crate::core::pipeline::RunSummary;
+use crate::types::{Severity, Violation};
+use serde::Serialize;
+use std::io::{self, Write};
+use std::path::Path;
+
+pub struct NdjsonReporter<W: Write + Send> {
+    out: W,
+}
+
+impl<W: Write + Send> NdjsonReporter<W> {
+    pub fn new(out: W) -> Self {
+        Self { out }
+    }
+}
+
+impl<W: Write + Send> Reporter for NdjsonReporter<W> {
+    fn report_file(&mut self, input_path: &Path, violations: &[Violation]) -> io::Result<()> {
+        for v in violations {
+            serde_json::to_writer(&mut self.out, &with_file(v, input_path))?;
+            self.out.write_all(b"\n")?;
+        }
+        self.out.flush()
+    }
+}
+
+#[derive(Serialize, Default, Clone, Copy)]
+struct SeverityCounts {
+    violations: usize,
+    errors: usize,
+    warnings: usize,
+    infos: usize,
+}
+
+impl SeverityCounts {
+    fn add(&mut self, sev: Severity) {
+        self.violations += 1;
+        match sev {
+            Severity::Error => self.errors += 1,
+            Severity::Warning => self.warnings += 1,
+            Severity::Info => self.infos += 1,
+        }
+    }
+
+    fn merge(&mut self, other: &SeverityCounts) {
+        self.violations += other.violations;
+        self.errors += other.errors;
+        self.warnings += other.warnings;
+        self.infos += other.infos;
+    }
+}
+
+#[derive(Serialize)]
+struct FileEntry {
+    path: String,
+    summary: SeverityCounts,
+    violations: Vec<Violation>,
+}
+
+#[derive(Serialize)]
+struct RunTotals {
+    files: usize,
+    #[serde(flatten)]
+    counts: SeverityCounts,
+    had_error: bool,
+}
+
+#[derive(Serialize)]
+struct JsonDocument<'a> {
+    files: &'a [FileEntry],
+    summary: RunTotals,
+}
+
+pub struct JsonReporter<W: Write + Send> {
+    out: W,
+    files: Vec<FileEntry>,
+}
+
+impl<W: Write + Send> JsonReporter<W> {
+    pub fn new(out: W) -> Self {
+        Self { out, files: Vec::new() }
+    }
+}
+
+impl<W: Write + Send> Reporter for JsonReporter<W> {
+    fn report_file(&mut self, input_path: &Path, violations: &[Violation]) -> io::Result<()> {
+        let mut summary = SeverityCounts::default();
+        let mut list = Vec::with_capacity(violations.len());
+        for v in violations {
+            summary.add(v.severity);
+            list.push(with_file(v, input_path));
+        }
+        self.files.push(FileEntry {
+            path: input_path.to_string_lossy().into_owned(),
+            summary,
+            violations: list,
+        });
+        Ok(())
+    }
+
+    fn finish(&mut self, run: &RunSummary) -> io::Result<()> {
+        self.files.sort_by(|a, b| a.path.cmp(&b.path));
+        let mut counts = SeverityCounts::default();
+        for file in &self.files {
+            counts.merge(&file.summary);
+        }
+        let doc = JsonDocument {
+            files: &self.files,
+            summary: RunTotals {
+                files: self.files.len(),
+                counts,
+                had_error: run.had_error,
+            },
+        };
+        serde_json::to_writer_pretty(&mut self.out, &doc)?;
+        self.out.write_all(b"\n")?;
+        self.out.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Location;
+
+    fn violation(rule_id: &str, severity: Severity, file: Option<&str>) -> Violation {
+        Violation {
+            rule_id: rule_id.to_string(),
+            severity,
+            message: "msg".to_string(),
+            location: Location {
+                line: 3,
+                col: 5,
+                end_line: 4,
+                end_col: 7,
+                file: file.map(str::to_string),
+            },
+            fixes: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn ndjson_writes_one_line_per_violation_with_file() {
+        let mut rep = NdjsonReporter::new(Vec::new());
+        let vs = vec![
+            violation("a", Severity::Warning, None),
+            violation("b", Severity::Error, Some("inc.svh")),
+        ];
+        rep.report_file(Path::new("top.sv"), &vs).unwrap();
+        let text = String::from_utf8(rep.out).unwrap();
+        let lines: Vec<serde_json::Value> = text.lines().map(|l| serde_json::from_str(l).unwrap()).collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["location"]["file"], "top.sv");
+        assert_eq!(lines[0]["location"]["end_line"], 4);
+        assert_eq!(lines[1]["location"]["file"], "inc.svh");
+    }
+
+    #[test]
+    fn json_document_has_per_file_summary() {
+        let mut rep = JsonReporter::new(Vec::new());
+        rep.report_file(
+            Path::new("b.sv"),
+            &[
+                violation("a", Severity::Warning, None),
+                violation("b", Severity::Error, None),
+            ],
+        )
+        .unwrap();
+        rep.report_file(Path::new("a.sv"), &[]).unwrap();
+        rep.finish(&RunSummary {
+            violations: 2,
+            had_error: false,
+        })
+        .unwrap();
+        let doc: serde_json::Value = serde_json::from_slice(&rep.out).unwrap();
+        assert_eq!(doc["files"][0]["path"], "a.sv");
+        assert_eq!(doc["files"][1]["summary"]["errors"], 1);
+        assert_eq!(doc["files"][1]["summary"]["warnings"], 1);
+        assert_eq!(doc["summary"]["files"], 2);
+        assert_eq!(doc["summary"]["violations"], 2);
+    }
+}
@@ -0,0 +1,197 @@
+use super::{severity_name, with_file, xml_escape, Reporter};
+use crate::config::RuleConfig;
+use crate::core::pipeline::RunSummary;
+use crate::types::{Severity, Violation};
+use std::io::{self, Write};
+use std::path::Path;
+
+pub struct JunitReporter<W: Write + Send> {
+    out: W,
+    enabled_rules: Vec<String>,
+    files: Vec<(String, Vec<Violation>)>,
+}
+
+struct TestCase<'a> {
+    rule_id: &'a str,
+    violations: Vec<&'a Violation>,
+}
+
+impl<W: Write + Send> JunitReporter<W> {
+    pub fn new(out: W, rules: &[RuleConfig]) -> Self {
+        let enabled_rules = rules.iter().filter(|r| r.enabled).map(|r| r.id.clone()).collect();
+        Self {
+            out,
+            enabled_rules,
+            files: Vec::new(),
+        }
+    }
+
+    fn cases_for<'a>(&'a self, violations: &'a [Violation]) -> Vec<TestCase<'a>> {
+        let mut cases: Vec<TestCase<'a>> = self
+            .enabled_rules
+            .iter()
+            .map(|id| TestCase {
+                rule_id: id.as_str(),
+                violations: Vec::new(),
+            })
+            .collect();
+        for v in violations {
+            match cases.iter_mut().find(|c| c.rule_id == v.rule_id) {
+                Some(case) => case.violations.push(v),
+                None => cases.push(TestCase {
+                    rule_id: v.rule_id.as_str(),
+                    violations: vec![v],
+                }),
+            }
+        }
+        cases
+    }
+}
+
+impl<W: Write + Send> Reporter for JunitReporter<W> {
+    fn report_file(&mut self, input_path: &Path, violations: &[Violation]) -> io::Result<()> {
+        let list = violations.iter().map(|v| with_file(v, input_path)).collect();
+        self.files.push((input_path.to_string_lossy().into_owned(), list));
+        Ok(())
+    }
+
+    fn finish(&mut self, _summary: &RunSummary) -> io::Result<()> {
+        self.files.sort_by(|a, b| a.0.cmp(&b.0));
+        let mut body = String::new();
+        let mut total_tests = 0usize;
+        let mut total_failures = 0usize;
+        for (path, violations) in &self.files {
+            let cases = self.cases_for(violations);
+            let failures = cases.iter().filter(|c| !c.violations.is_empty()).count();
+            total_tests += cases.len();
+            total_failures += failures;
+            let name = xml_escape(path);
+            body.push_str(&format!(
+                "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"0\">\n",
+                name,
+                cases.len(),
+                failures
+            ));
+            for case in &cases {
+                let rule = xml_escape(case.rule_id);
+                if case.violations.is_empty() {
+                    body.push_str(&format!("    <testcase name=\"{}\" classname=\"{}\"/>\n", rule, name));
+                    continue;
+                }
+                body.push_str(&format!("    <testcase name=\"{}\" classname=\"{}\">\n", rule, name));
+                let worst = case
+                    .violations
+                    .iter()
+                    .map(|v| v.severity)
+                    .min_by_key(|sev| severity_rank(*sev))
+                    .unwrap_or(Severity::Warning);
+                body.push_str(&format!(
+                    "      <failure type=\"{}\" message=\"{} violation(s)\">",
+                    severity_name(worst),
+                    case.violations.len()
+                ));
+                for v in &case.violations {
+                    let line = format!(
+                        "{}:{}:{}: [{}] {}\n",
+                        v.location.file.as_deref().unwrap_or(path),
+                        v.location.line.max(1),
+                        v.location.col.max(1),
+                        severity_name(v.severity),
+                        v.message
+                    );
+                    body.push_str(&xml_escape(&line));
+                }
+                body.push_str("</failure>\n    </testcase>\n");
+            }
+            body.push_str("  </testsuite>\n");
+        }
+        writeln!(self.out, "<?xml version=\"1.0\" encoding=\"UTF-8\"?>")?;
+        writeln!(
+            self.out,
+            "<testsuites name=\"sv-mint\" tests=\"{}\" failures=\"{}\">",
+            total_tests, total_failures
+        )?;
+        self.out.write_all(body.as_bytes())?;
+        writeln!(self.out, "</testsuites>")?;
+        self.out.flush()
+    }
+}
+
+fn severity_rank(sev: Severity) -> u8 {
+    match sev {
+        Severity::Error => 0,
+        Severity::Warning => 1,
+        Severity::Info => 2,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::output::reported_rules;
+    use crate::types::{Location, Stage};
+
+    #[test]
+    fn one_testcase_per_enabled_rule_with_failures() {
+        let rules: Vec<RuleConfig> = ["a", "b", "c"]
+            .iter()
+            .map(|id| RuleConfig {
+                id: id.to_string(),
+                script: format!("{id}.raw.py"),
+                native: false,
+                host: None,
+                stage: Some(Stage::RawText),
+                enabled: *id != "c",
+                severity: None,
+                options: Default::default(),
+            })
+            .collect();
+        let mut rep = JunitReporter::new(Vec::new(), &rules);
+        let v = Violation {
+            rule_id: "b".to_string(),
+            severity: Severity::Warning,
+            message: "x < y & z".to_string(),
+            location: Location {
+                line: 4,
+                col: 2,
+                end_line: 4,
+                end_col: 3,
+                file: None,
+            },
+            fixes: Vec::new(),
+        };
+        rep.report_file(Path::new("top.sv"), &[v]).unwrap();
+        rep.finish(&RunSummary {
+            violations: 1,
+            had_error: false,
+        })
+        .unwrap();
+        let xml = String::from_utf8(rep.out).unwrap();
+        assert!(xml.contains("<testsuites name=\"sv-mint\" tests=\"2\" failures=\"1\">"));
+        assert!(xml.contains("<testsuite name=\"top.sv\" tests=\"2\" failures=\"1\" errors=\"0\">"));
+        assert!(xml.contains("<testcase name=\"a\" classname=\"top.sv\"/>"));
+        assert!(xml.contains("top.sv:4:2: [warning] x &lt; y &amp; z"));
+        assert!(!xml.contains("name=\"c\""));
+    }
+
+    #[test]
+    fn testcases_cover_rules_enabled_in_any_config_or_override() {
+        let a = crate::config::load("[[rule]]\nid = \"a\"\n\n[[rule]]\nid = \"c\"\nenabled = false\n").unwrap();
+        let b = crate::config::load(
+            "[[rule]]\nid = \"b\"\nenabled = false\n\n[[rule]]\nid = \"d\"\nenabled = false\n\n[[override]]\npaths = [\"tb/**\"]\n[[override.rule]]\nid = \"b\"\nenabled = true\n",
+        )
+        .unwrap();
+        let mut rep = JunitReporter::new(Vec::new(), &reported_rules(&[&a, &b]));
+        rep.report_file(Path::new("top.sv"), &[]).unwrap();
+        rep.finish(&RunSummary {
+            violations: 0,
+            had_error: false,
+        })
+        .unwrap();
+        let xml = String::from_utf8(rep.out).unwrap();
+        assert!(xml.contains("<testcase name=\"a\" classname=\"top.sv\"/>"), "{}", xml);
+        assert!(xml.contains("<testcase name=\"b\" classname=\"top.sv\"/>"), "{}", xml);
+        assert!(!xml.contains("name=\"c\""), "{}", xml);
+        assert!(!xml.contains("name=\"d\""), "{}", xml);
+    }
+}
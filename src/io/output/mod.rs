@@ -0,0 +1,142 @@
+mod checkstyle;
+mod json;
+mod junit;
+mod sarif;
+mod text;
+
+pub use checkstyle::CheckstyleReporter;
+pub use json::{JsonReporter, NdjsonReporter};
+pub use junit::JunitReporter;
+pub use sarif::SarifReporter;
+pub use text::TextReporter;
+
+use crate::config::{Config, OutputFormat, RuleConfig};
+use crate::core::pipeline::RunSummary;
+use crate::errors::{ConfigError, OutputError};
+use crate::types::{Severity, Violation};
+use std::borrow::Cow;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+pub trait Reporter: Send {
+    fn report_file(&mut self, input_path: &Path, violations: &[Violation]) -> io::Result<()>;
+
+    fn finish(&mut self, _summary: &RunSummary) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+pub fn reporter_for(cfg: &Config) -> Box<dyn Reporter> {
+    build_reporter(cfg.output.format, &[cfg])
+}
+
+pub fn reporter_for_all(configs: &[&Config]) -> Result<Box<dyn Reporter>, ConfigError> {
+    let format = configs.first().map(|cfg| cfg.output.format).unwrap_or_default();
+    if let Some(other) = configs.iter().find(|cfg| cfg.output.format != format) {
+        return Err(ConfigError::InvalidValue {
+            detail: format!(
+                "configs set different [output] formats ({} and {}); pass --format",
+                format.as_str(),
+                other.output.format.as_str()
+            ),
+        });
+    }
+    Ok(build_reporter(format, configs))
+}
+
+fn build_reporter(format: OutputFormat, configs: &[&Config]) -> Box<dyn Reporter> {
+    match format {
+        OutputFormat::Text => Box::new(TextReporter),
+        OutputFormat::Json => Box::new(JsonReporter::new(io::stdout())),
+        OutputFormat::Ndjson => Box::new(NdjsonReporter::new(io::stdout())),
+        OutputFormat::Sarif => Box::new(SarifReporter::new(io::stdout(), &reported_rules(configs))),
+        OutputFormat::Junit => Box::new(JunitReporter::new(io::stdout(), &reported_rules(configs))),
+        OutputFormat::Checkstyle => Box::new(CheckstyleReporter::new(io::stdout())),
+    }
+}
+
+pub fn reported_rules(configs: &[&Config]) -> Vec<RuleConfig> {
+    let mut rules: Vec<RuleConfig> = Vec::new();
+    for cfg in configs {
+        for rule in &cfg.rule {
+            let enabled = rule.enabled
+                || cfg
+                    .overrides
+                    .iter()
+                    .any(|o| o.rule.iter().any(|r| r.id == rule.id && r.enabled == Some(true)));
+            match rules.iter_mut().find(|r| r.id == rule.id) {
+                Some(existing) => {
+                    existing.enabled |= enabled;
+                    if existing.severity.is_none() {
+                        existing.severity = rule.severity.clone();
+                    }
+                }
+                None => rules.push(RuleConfig {
+                    enabled,
+                    ..rule.clone()
+                }),
+            }
+        }
+    }
+    rules
+}
+
+pub fn read_file_to_string(path: &Path) -> Result<String, OutputError> {
+    let bytes = fs::read(path).map_err(|e| OutputError::ReadFailed {
+        path: path.display().to_string(),
+        source: Some(e),
+    })?;
+    let s = String::from_utf8(bytes).map_err(|_| OutputError::InvalidUtf8 {
+        path: path.display().to_string(),
+        source: None,
+    })?;
+    Ok(s)
+}
+
+pub fn print_violations(violations: &[Violation], input_path: &Path) {
+    let fallback_path = input_path.display().to_string();
+    for v in violations {
+        let sev = severity_name(v.severity);
+        let line = v.location.line.max(1);
+        let col = v.location.col.max(1);
+        let path = v
+            .location
+            .file
+            .as_deref()
+            .map(Cow::from)
+            .unwrap_or_else(|| Cow::Borrowed(fallback_path.as_str()));
+        println!("{}:{}:{}: [{}] {}: {}", path, line, col, sev, v.rule_id, v.message);
+    }
+}
+
+pub(crate) fn severity_name(sev: Severity) -> &'static str {
+    match sev {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Info => "info",
+    }
+}
+
+pub(crate) fn with_file(v: &Violation, input_path: &Path) -> Violation {
+    let mut out = v.clone();
+    if out.location.file.is_none() {
+        out.location.file = Some(input_path.to_string_lossy().into_owned());
+    }
+    out
+}
+
+pub(crate) fn xml_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for ch in s.chars() {
+        match ch {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            _ => out.push(ch),
+        }
+    }
+    out
+}
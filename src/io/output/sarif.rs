@@ -0,0 +1,344 @@
+use super::{with_file, Reporter};
+use crate::config::RuleConfig;
+use crate::core::pipeline::RunSummary;
+use crate::types::{Severity, Violation};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::io::{self, Write};
+use std::path::Path;
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+
+pub struct SarifReporter<W: Write + Send> {
+    out: W,
+    rules: Vec<ReportingDescriptor>,
+    rule_index: HashMap<String, usize>,
+    results: Vec<(String, SarifResult)>,
+    notifications: Vec<(String, Notification)>,
+}
+
+#[derive(Serialize)]
+struct SarifLog<'a> {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: [SarifRun<'a>; 1],
+}
+
+#[derive(Serialize)]
+struct SarifRun<'a> {
+    tool: Tool<'a>,
+    invocations: [Invocation<'a>; 1],
+    results: Vec<&'a SarifResult>,
+}
+
+#[derive(Serialize)]
+struct Tool<'a> {
+    driver: Driver<'a>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Driver<'a> {
+    name: &'static str,
+    version: &'static str,
+    rules: &'a [ReportingDescriptor],
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ReportingDescriptor {
+    id: String,
+    default_configuration: RuleConfiguration,
+}
+
+#[derive(Serialize)]
+struct RuleConfiguration {
+    enabled: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    level: Option<&'static str>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Invocation<'a> {
+    execution_successful: bool,
+    tool_execution_notifications: Vec<&'a Notification>,
+}
+
+#[derive(Serialize)]
+struct Notification {
+    descriptor: DescriptorRef,
+    level: &'static str,
+    message: Message,
+    locations: Vec<SarifLocation>,
+}
+
+#[derive(Serialize)]
+struct DescriptorRef {
+    id: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    rule_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_index: Option<usize>,
+    level: &'static str,
+    message: Message,
+    locations: Vec<SarifLocation>,
+}
+
+#[derive(Serialize)]
+struct Message {
+    text: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifLocation {
+    physical_location: PhysicalLocation,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PhysicalLocation {
+    artifact_location: ArtifactLocation,
+    region: Region,
+}
+
+#[derive(Serialize)]
+struct ArtifactLocation {
+    uri: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Region {
+    start_line: u32,
+    start_column: u32,
+    end_line: u32,
+    end_column: u32,
+}
+
+impl<W: Write + Send> SarifReporter<W> {
+    pub fn new(out: W, rules: &[RuleConfig]) -> Self {
+        let mut descriptors = Vec::with_capacity(rules.len());
+        let mut rule_index = HashMap::new();
+        for rule in rules {
+            rule_index.insert(rule.id.clone(), descriptors.len());
+            descriptors.push(ReportingDescriptor {
+                id: rule.id.clone(),
+                default_configuration: RuleConfiguration {
+                    enabled: rule.enabled,
+                    level: rule.severity.as_deref().and_then(level_from_name),
+                },
+            });
+        }
+        Self {
+            out,
+            rules: descriptors,
+            rule_index,
+            results: Vec::new(),
+            notifications: Vec::new(),
+        }
+    }
+}
+
+impl<W: Write + Send> Reporter for SarifReporter<W> {
+    fn report_file(&mut self, input_path: &Path, violations: &[Violation]) -> io::Result<()> {
+        let key = input_path.to_string_lossy().into_owned();
+        for v in violations {
+            let v = with_file(v, input_path);
+            let locations = vec![sarif_location(&v)];
+            let message = Message { text: v.message };
+            if v.rule_id.starts_with("sys.") {
+                self.notifications.push((
+                    key.clone(),
+                    Notification {
+                        descriptor: DescriptorRef { id: v.rule_id },
+                        level: sarif_level(v.severity),
+                        message,
+                        locations,
+                    },
+                ));
+            } else {
+                self.results.push((
+                    key.clone(),
+                    SarifResult {
+                        rule_index: self.rule_index.get(&v.rule_id).copied(),
+                        rule_id: v.rule_id,
+                        level: sarif_level(v.severity),
+                        message,
+                        locations,
+                    },
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    fn finish(&mut self, summary: &RunSummary) -> io::Result<()> {
+        self.results.sort_by(|a, b| a.0.cmp(&b.0));
+        self.notifications.sort_by(|a, b| a.0.cmp(&b.0));
+        let log = SarifLog {
+            schema: SARIF_SCHEMA,
+            version: SARIF_VERSION,
+            runs: [SarifRun {
+                tool: Tool {
+                    driver: Driver {
+                        name: "sv-mint",
+                        version: env!("CARGO_PKG_VERSION"),
+                        rules: &self.rules,
+                    },
+                },
+                invocations: [Invocation {
+                    execution_successful: !summary.had_error,
+                    tool_execution_notifications: self.notifications.iter().map(|(_, n)| n).collect(),
+                }],
+                results: self.results.iter().map(|(_, r)| r).collect(),
+            }],
+        };
+        serde_json::to_writer_pretty(&mut self.out, &log)?;
+        self.out.write_all(b"\n")?;
+        self.out.flush()
+    }
+}
+
+fn sarif_location(v: &Violation) -> SarifLocation {
+    let loc = &v.location;
+    let line = loc.line.max(1);
+    let col = loc.col.max(1);
+    SarifLocation {
+        physical_location: PhysicalLocation {
+            artifact_location: ArtifactLocation {
+                uri: loc.file.as_deref().unwrap_or_default().replace('\\', "/"),
+            },
+            region: Region {
+                start_line: line,
+                start_column: col,
+                end_line: loc.end_line.max(line),
+                end_column: loc.end_col.max(1),
+            },
+        },
+    }
+}
+
+fn sarif_level(sev: Severity) -> &'static str {
+    match sev {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Info => "note",
+    }
+}
+
+fn level_from_name(name: &str) -> Option<&'static str> {
+    match name {
+        "error" => Some("error"),
+        "warning" => Some("warning"),
+        "info" => Some("note"),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::output::reported_rules;
+    use crate::types::{Location, Stage};
+
+    fn rule(id: &str, severity: Option<&str>) -> RuleConfig {
+        RuleConfig {
+            id: id.to_string(),
+            script: format!("{id}.raw.py"),
+            native: false,
+            host: None,
+            stage: Some(Stage::RawText),
+            enabled: true,
+            severity: severity.map(str::to_string),
+            options: Default::default(),
+        }
+    }
+
+    fn violation(rule_id: &str, severity: Severity) -> Violation {
+        Violation {
+            rule_id: rule_id.to_string(),
+            severity,
+            message: format!("{rule_id} fired"),
+            location: Location {
+                line: 2,
+                col: 3,
+                end_line: 2,
+                end_col: 9,
+                file: None,
+            },
+            fixes: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn splits_results_and_sys_notifications() {
+        let rules = vec![rule("a", None), rule("b", Some("info"))];
+        let mut rep = SarifReporter::new(Vec::new(), &rules);
+        rep.report_file(
+            Path::new("dir\\top.sv"),
+            &[
+                violation("b", Severity::Info),
+                violation("sys.parse.failed", Severity::Error),
+            ],
+        )
+        .unwrap();
+        rep.finish(&RunSummary {
+            violations: 2,
+            had_error: false,
+        })
+        .unwrap();
+        let log: serde_json::Value = serde_json::from_slice(&rep.out).unwrap();
+        assert_eq!(log["version"], "2.1.0");
+        let run = &log["runs"][0];
+        assert_eq!(run["tool"]["driver"]["rules"][1]["id"], "b");
+        assert_eq!(
+            run["tool"]["driver"]["rules"][1]["defaultConfiguration"]["level"],
+            "note"
+        );
+        let results = run["results"].as_array().unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0]["ruleId"], "b");
+        assert_eq!(results[0]["ruleIndex"], 1);
+        assert_eq!(results[0]["level"], "note");
+        let region = &results[0]["locations"][0]["physicalLocation"]["region"];
+        assert_eq!(region["startLine"], 2);
+        assert_eq!(region["endColumn"], 9);
+        assert_eq!(
+            results[0]["locations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+            "dir/top.sv"
+        );
+        let notes = run["invocations"][0]["toolExecutionNotifications"].as_array().unwrap();
+        assert_eq!(notes.len(), 1);
+        assert_eq!(notes[0]["descriptor"]["id"], "sys.parse.failed");
+    }
+
+    #[test]
+    fn descriptors_cover_every_config_group() {
+        let a = crate::config::load("[[rule]]\nid = \"a\"\n").unwrap();
+        let b = crate::config::load("[[rule]]\nid = \"b\"\nenabled = false\n\n[[override]]\npaths = [\"tb/**\"]\n[[override.rule]]\nid = \"b\"\nenabled = true\n").unwrap();
+        let mut rep = SarifReporter::new(Vec::new(), &reported_rules(&[&a, &b]));
+        rep.report_file(Path::new("tb/top.sv"), &[violation("b", Severity::Warning)])
+            .unwrap();
+        rep.finish(&RunSummary {
+            violations: 1,
+            had_error: false,
+        })
+        .unwrap();
+        let log: serde_json::Value = serde_json::from_slice(&rep.out).unwrap();
+        let run = &log["runs"][0];
+        assert_eq!(run["tool"]["driver"]["rules"][1]["id"], "b");
+        assert_eq!(
+            run["tool"]["driver"]["rules"][1]["defaultConfiguration"]["enabled"],
+            true
+        );
+        assert_eq!(run["results"][0]["ruleIndex"], 1);
+    }
+}
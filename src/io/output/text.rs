@@ -0,0 +1,13 @@
+use super::{print_violations, Reporter};
+use crate::types::Violation;
+use std::io;
+use std::path::Path;
+
+pub struct TextReporter;
+
+impl Reporter for TextReporter {
+    fn report_file(&mut self, input_path: &Path, violations: &[Violation]) -> io::Result<()> {
+        print_violations(violations, input_path);
+        Ok(())
+    }
+}
@@ -54,3 +54,42 @@ pub fn truncate_preview_utf8(s: &str, max: usize) -> String {
     t.push_str(" ...");
     t
 }
+
+pub struct ContentHasher(u64);
+
+impl ContentHasher {
+    const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+    const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    pub fn new() -> Self {
+        Self(Self::FNV_OFFSET)
+    }
+
+    pub fn update(&mut self, bytes: &[u8]) {
+        for b in bytes {
+            self.0 ^= u64::from(*b);
+            self.0 = self.0.wrapping_mul(Self::FNV_PRIME);
+        }
+    }
+
+    pub fn field(&mut self, value: &str) {
+        self.update(value.as_bytes());
+        self.update(&[0xff]);
+    }
+
+    pub fn finish(&self) -> String {
+        format!("{:016x}", self.0)
+    }
+}
+
+impl Default for ContentHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub fn content_hash(bytes: &[u8]) -> String {
+    let mut hasher = ContentHasher::new();
+    hasher.update(bytes);
+    hasher.finish()
+}
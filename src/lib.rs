@@ -1,12 +1,16 @@
 pub mod core;
 pub use core::errors;
+pub use core::linter;
 pub use core::types;
 
 pub mod io;
 pub use io::config;
+pub use io::filelist;
+pub use io::inputs;
 pub use io::output;
 pub use io::textutil;
 
+pub mod lsp;
 pub mod plugin;
 
 pub mod sv;
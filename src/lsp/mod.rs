@@ -0,0 +1,272 @@
+mod position;
+mod transport;
+
+pub use transport::{read_message, write_message};
+
+use crate::config::{discover_config, Config, InputText};
+use crate::core::linter::Linter;
+use crate::types::{Severity, Violation};
+use anyhow::Result;
+use position::PositionMap;
+use serde_json::{json, Value};
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::io::{BufRead, Write};
+use std::path::{Path, PathBuf};
+
+const METHOD_NOT_FOUND: i64 = -32601;
+
+struct Document {
+    path: PathBuf,
+    text: InputText,
+    violations: Vec<Violation>,
+}
+
+pub type ConfigLoader<'a> = Box<dyn FnMut(&Option<PathBuf>) -> Result<Config> + 'a>;
+
+pub struct Server<'a, W: Write> {
+    config: Option<PathBuf>,
+    load_config: ConfigLoader<'a>,
+    linters: HashMap<Option<PathBuf>, Linter>,
+    writer: W,
+    documents: HashMap<String, Document>,
+    shutdown: bool,
+}
+
+impl<'a, W: Write> Server<'a, W> {
+    pub fn new(writer: W, config: Option<PathBuf>, load_config: ConfigLoader<'a>) -> Self {
+        Self {
+            config,
+            load_config,
+            linters: HashMap::new(),
+            writer,
+            documents: HashMap::new(),
+            shutdown: false,
+        }
+    }
+
+    pub fn run<R: BufRead>(&mut self, mut reader: R) -> Result<bool> {
+        while let Some(message) = read_message(&mut reader)? {
+            let method = message.get("method").and_then(Value::as_str).unwrap_or("");
+            if method == "exit" {
+                break;
+            }
+            let params = message.get("params").cloned().unwrap_or(Value::Null);
+            match message.get("id").cloned() {
+                Some(id) if !method.is_empty() => self.handle_request(id, method, &params)?,
+                Some(_) => {}
+                None => self.handle_notification(method, &params)?,
+            }
+        }
+        Ok(self.shutdown)
+    }
+
+    fn handle_request(&mut self, id: Value, method: &str, params: &Value) -> Result<()> {
+        let result = match method {
+            "initialize" => json!({
+                "capabilities": {
+                    "textDocumentSync": {
+                        "openClose": true,
+                        "change": 1,
+                        "save": {"includeText": true},
+                    },
+                    "codeActionProvider": {"codeActionKinds": ["quickfix"]},
+                },
+                "serverInfo": {"name": "sv-mint", "version": env!("CARGO_PKG_VERSION")},
+            }),
+            "shutdown" => {
+                self.shutdown = true;
+                Value::Null
+            }
+            "textDocument/codeAction" => self.code_actions(params),
+            _ => {
+                return self.send(json!({
+                    "jsonrpc": "2.0",
+                    "id": id,
+                    "error": {"code": METHOD_NOT_FOUND, "message": format!("unhandled method {method}")},
+                }));
+            }
+        };
+        self.send(json!({"jsonrpc": "2.0", "id": id, "result": result}))
+    }
+
+    fn handle_notification(&mut self, method: &str, params: &Value) -> Result<()> {
+        let uri = params
+            .pointer("/textDocument/uri")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string();
+        let text = match method {
+            "textDocument/didOpen" => params.pointer("/textDocument/text"),
+            "textDocument/didChange" => params
+                .get("contentChanges")
+                .and_then(Value::as_array)
+                .and_then(|changes| changes.last())
+                .and_then(|change| change.get("text")),
+            "textDocument/didSave" => params.get("text"),
+            "textDocument/didClose" => {
+                self.documents.remove(&uri);
+                return self.publish(&uri, Vec::new());
+            }
+            _ => return Ok(()),
+        };
+        if let Some(text) = text.and_then(Value::as_str) {
+            let doc = Document {
+                path: uri_to_path(&uri),
+                text: InputText::new(text.to_string()),
+                violations: Vec::new(),
+            };
+            self.documents.insert(uri.clone(), doc);
+        }
+        self.lint(&uri)
+    }
+
+    fn lint(&mut self, uri: &str) -> Result<()> {
+        let Some(doc) = self.documents.get_mut(uri) else {
+            return Ok(());
+        };
+        let key = self.config.clone().or_else(|| discover_config(&doc.path));
+        let linter = match self.linters.entry(key) {
+            Entry::Occupied(entry) => Ok(entry.into_mut()),
+            Entry::Vacant(entry) => (self.load_config)(entry.key()).map(|cfg| entry.insert(Linter::new(cfg))),
+        };
+        match linter.and_then(|linter| linter.lint_source(&doc.path, &doc.text.raw)) {
+            Ok(report) => {
+                let own = doc.path.to_string_lossy().into_owned();
+                doc.violations = report
+                    .violations
+                    .into_iter()
+                    .filter(|v| v.location.file.as_ref().is_none_or(|f| *f == own))
+                    .collect();
+                let map = PositionMap::new(&doc.text.normalized);
+                let diagnostics = doc.violations.iter().map(|v| diagnostic(&map, v)).collect();
+                self.publish(uri, diagnostics)
+            }
+            Err(e) => {
+                let message = format!("{}: {}", doc.path.display(), e);
+                self.send(json!({
+                    "jsonrpc": "2.0",
+                    "method": "window/logMessage",
+                    "params": {"type": 1, "message": message},
+                }))
+            }
+        }
+    }
+
+    fn code_actions(&self, params: &Value) -> Value {
+        let uri = params
+            .pointer("/textDocument/uri")
+            .and_then(Value::as_str)
+            .unwrap_or_default();
+        let Some(doc) = self.documents.get(uri) else {
+            return json!([]);
+        };
+        let line_of = |ptr: &str| params.pointer(ptr).and_then(Value::as_u64).unwrap_or(0);
+        let (first, last) = (line_of("/range/start/line"), line_of("/range/end/line"));
+        let map = PositionMap::new(&doc.text.normalized);
+        let mut actions = Vec::new();
+        for v in &doc.violations {
+            let (start, end) = (
+                u64::from(v.location.line.saturating_sub(1)),
+                u64::from(v.location.end_line.saturating_sub(1)),
+            );
+            if end < first || start > last {
+                continue;
+            }
+            for fix in &v.fixes {
+                let edits: Vec<Value> = fix
+                    .edits
+                    .iter()
+                    .map(|e| {
+                        json!({
+                            "range": {"start": map.offset_to_position(e.start), "end": map.offset_to_position(e.end)},
+                            "newText": e.replacement,
+                        })
+                    })
+                    .collect();
+                let title = fix.description.clone().unwrap_or_else(|| format!("Fix {}", v.rule_id));
+                actions.push(json!({
+                    "title": title,
+                    "kind": "quickfix",
+                    "diagnostics": [diagnostic(&map, v)],
+                    "edit": {"changes": {uri: edits}},
+                }));
+            }
+        }
+        Value::Array(actions)
+    }
+
+    fn publish(&mut self, uri: &str, diagnostics: Vec<Value>) -> Result<()> {
+        self.send(json!({
+            "jsonrpc": "2.0",
+            "method": "textDocument/publishDiagnostics",
+            "params": {"uri": uri, "diagnostics": diagnostics},
+        }))
+    }
+
+    fn send(&mut self, message: Value) -> Result<()> {
+        write_message(&mut self.writer, &message)?;
+        Ok(())
+    }
+}
+
+fn diagnostic(map: &PositionMap<'_>, v: &Violation) -> Value {
+    let loc = &v.location;
+    let severity = match v.severity {
+        Severity::Error => 1,
+        Severity::Warning => 2,
+        Severity::Info => 3,
+    };
+    json!({
+        "range": {
+            "start": map.line_col_to_position(loc.line, loc.col),
+            "end": map.line_col_to_position(loc.end_line, loc.end_col),
+        },
+        "severity": severity,
+        "code": v.rule_id,
+        "source": "sv-mint",
+        "message": v.message,
+    })
+}
+
+fn uri_to_path(uri: &str) -> PathBuf {
+    let Some(rest) = uri.strip_prefix("file://") else {
+        return PathBuf::from(uri);
+    };
+    let bytes = rest.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes.get(i + 1..i + 3).and_then(|h| std::str::from_utf8(h).ok());
+        match (bytes[i], hex.and_then(|h| u8::from_str_radix(h, 16).ok())) {
+            (b'%', Some(b)) => {
+                decoded.push(b);
+                i += 3;
+            }
+            (b, _) => {
+                decoded.push(b);
+                i += 1;
+            }
+        }
+    }
+    let path = String::from_utf8_lossy(&decoded).into_owned();
+    let path = match path.as_bytes() {
+        [b'/', drive, b':', ..] if drive.is_ascii_alphabetic() => path[1..].to_string(),
+        _ => path,
+    };
+    Path::new(&path).to_path_buf()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn file_uris_decode_to_paths() {
+        assert_eq!(
+            uri_to_path("file:///tmp/my%20rtl/top.sv"),
+            PathBuf::from("/tmp/my rtl/top.sv")
+        );
+        assert_eq!(uri_to_path("file:///C:/rtl/top.sv"), PathBuf::from("C:/rtl/top.sv"));
+    }
+}
@@ -0,0 +1,45 @@
+use serde_json::{json, Value};
+
+pub struct PositionMap<'a> {
+    text: &'a str,
+    starts: Vec<usize>,
+}
+
+impl<'a> PositionMap<'a> {
+    pub fn new(text: &'a str) -> Self {
+        let mut starts = vec![0];
+        starts.extend(text.match_indices('\n').map(|(i, _)| i + 1));
+        Self { text, starts }
+    }
+
+    pub fn offset_to_position(&self, offset: usize) -> Value {
+        let mut offset = offset.min(self.text.len());
+        while !self.text.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        let line = self.starts.partition_point(|&s| s <= offset) - 1;
+        let character = self.text[self.starts[line]..offset].encode_utf16().count();
+        json!({"line": line, "character": character})
+    }
+
+    pub fn line_col_to_position(&self, line: u32, col: u32) -> Value {
+        let idx = (line.max(1) as usize - 1).min(self.starts.len() - 1);
+        let line_end = self.starts.get(idx + 1).map_or(self.text.len(), |next| next - 1);
+        let offset = (self.starts[idx] + col.max(1) as usize - 1).min(line_end);
+        self.offset_to_position(offset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn byte_columns_become_utf16_characters() {
+        let map = PositionMap::new("// é😀\nwire w;\n");
+        assert_eq!(map.line_col_to_position(1, 10), json!({"line": 0, "character": 6}));
+        assert_eq!(map.line_col_to_position(2, 6), json!({"line": 1, "character": 5}));
+        assert_eq!(map.offset_to_position(10), json!({"line": 1, "character": 0}));
+        assert_eq!(map.line_col_to_position(9, 1), json!({"line": 2, "character": 0}));
+    }
+}
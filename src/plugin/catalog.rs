@@ -0,0 +1,127 @@
+use crate::config::Config;
+use crate::core::errors::PluginError;
+use crate::core::native;
+use crate::plugin::client::RuleMeta;
+use crate::plugin::HostPool;
+use crate::plugin_scripts::resolve_script_path;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Clone, Debug, Serialize)]
+pub struct RuleInfo {
+    pub id: String,
+    pub stage: String,
+    pub script: String,
+    pub enabled: bool,
+    pub severity: Option<String>,
+    pub description: Option<String>,
+    pub rationale: Option<String>,
+    pub tags: Vec<String>,
+    pub docs_url: Option<String>,
+}
+
+pub struct RuleExample {
+    pub path: PathBuf,
+    pub good: bool,
+    pub source: String,
+}
+
+pub fn describe_rules(cfg: &Config) -> Result<Vec<RuleInfo>, PluginError> {
+    let mut metas: HashMap<String, RuleMeta> = HashMap::new();
+    if cfg.rule.iter().any(|r| !r.native) {
+        let mut host = HostPool::start_all(cfg)?;
+        metas.extend(host.describe()?.into_iter().map(|m| (m.id.clone(), m)));
+    }
+    let mut out: Vec<RuleInfo> = cfg
+        .rule
+        .iter()
+        .map(|rule| {
+            let meta = if rule.native {
+                native::builtin().get(&rule.id).map(native_meta).unwrap_or_default()
+            } else {
+                metas.get(&rule.id).cloned().unwrap_or_default()
+            };
+            RuleInfo {
+                id: rule.id.clone(),
+                stage: rule.stage().as_str().to_string(),
+                script: if rule.native {
+                    "native".to_string()
+                } else {
+                    resolve_script_path(cfg, &rule.script)
+                },
+                enabled: rule.enabled,
+                severity: rule.severity.clone().or(meta.default_severity),
+                description: meta.description,
+                rationale: meta.rationale,
+                tags: meta.tags,
+                docs_url: meta.docs_url,
+            }
+        })
+        .collect();
+    out.sort_by(|a, b| a.id.cmp(&b.id));
+    Ok(out)
+}
+
+fn native_meta(rule: &dyn native::Rule) -> RuleMeta {
+    RuleMeta {
+        id: rule.id().to_string(),
+        description: Some(rule.description().to_string()),
+        rationale: rule.rationale().map(str::to_string),
+        default_severity: Some("warning".to_string()),
+        tags: rule.tags().iter().map(|t| t.to_string()).collect(),
+        docs_url: None,
+        script: None,
+    }
+}
+
+pub fn rule_examples(cfg: &Config, rule: &RuleInfo) -> Vec<RuleExample> {
+    let Some(dir) = fixture_roots(cfg, rule)
+        .into_iter()
+        .map(|root| root.join("fixtures").join("rules").join(&rule.id))
+        .find(|dir| dir.is_dir())
+    else {
+        return Vec::new();
+    };
+    let Ok(entries) = fs::read_dir(&dir) else {
+        return Vec::new();
+    };
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.extension().is_some_and(|ext| ext == "sv"))
+        .collect();
+    paths.sort();
+    let mut out: Vec<RuleExample> = paths
+        .into_iter()
+        .filter_map(|path| {
+            let stem = path.file_stem()?.to_string_lossy().to_lowercase();
+            let good = if stem.starts_with("good") {
+                true
+            } else if stem.starts_with("bad") {
+                false
+            } else {
+                return None;
+            };
+            let source = fs::read_to_string(&path).ok()?;
+            Some(RuleExample { path, good, source })
+        })
+        .collect();
+    out.sort_by_key(|e| !e.good);
+    out
+}
+
+fn fixture_roots(cfg: &Config, rule: &RuleInfo) -> Vec<PathBuf> {
+    let mut roots = Vec::new();
+    if let Some(root) = Path::new(&rule.script).parent().and_then(Path::parent) {
+        roots.push(root.to_path_buf());
+    }
+    if let Some(root) = cfg.plugin.normalized_root.as_deref().and_then(Path::parent) {
+        roots.push(root.to_path_buf());
+    }
+    if let Ok(cwd) = std::env::current_dir() {
+        roots.push(cwd);
+    }
+    roots
+}
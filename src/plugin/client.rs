@@ -1,13 +1,16 @@
-use crate::config::Config;
+use crate::config::{Config, RuleCrashPolicy};
 use crate::core::errors::PluginError;
 use crate::core::payload::StagePayload;
+use crate::core::profile::RuleTiming;
 use crate::diag::event::{Ev, Event};
 use crate::diag::logging::log_event;
 use crate::plugin_scripts::{collect_script_specs, resolve_script_path, ScriptSpec};
+use crate::sv::cst_ir::CST_IR_SCHEMA;
+use crate::sv::model::AST_SCHEMA_VERSION;
 use crate::types::{Severity, Stage, Violation};
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
-use std::collections::{BTreeMap, HashMap};
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::path::Path;
 use std::process::Stdio;
 use std::sync::{Arc, Mutex};
@@ -18,8 +21,14 @@ use tokio::runtime::Runtime;
 use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
 use tokio::time;
 
-pub struct PythonHost {
+pub const PROTOCOL_VERSION: u32 = 1;
+pub const RULE_CRASHED: &str = "sys.plugin.rule_crashed";
+
+pub struct PluginHost {
     runtime: Runtime,
+    program: String,
+    args: Vec<String>,
+    scripts: Vec<ScriptSpec>,
     child: Child,
     stdin: ChildStdin,
     stdout_rx: UnboundedReceiver<String>,
@@ -29,6 +38,30 @@ pub struct PythonHost {
     snippet_limit: usize,
     severity_override: HashMap<String, Severity>,
     rule_enabled: HashMap<String, bool>,
+    crash_severity: Severity,
+    capabilities: Capabilities,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Capabilities {
+    pub fixes: bool,
+    pub metadata: bool,
+}
+
+impl Capabilities {
+    fn from_names(names: &[String]) -> Self {
+        let has = |name: &str| names.iter().any(|n| n == name);
+        Self {
+            fixes: has("fixes"),
+            metadata: has("metadata"),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct PayloadSchemas {
+    cst_ir: u32,
+    ast: u32,
 }
 
 #[derive(Serialize)]
@@ -36,20 +69,25 @@ struct ScriptInit<'a> {
     path: &'a str,
     stages: &'a [String],
     stage_rules: &'a BTreeMap<String, Vec<String>>,
+    options: &'a BTreeMap<String, Map<String, Value>>,
 }
 
-#[derive(Serialize)]
+#[derive(Clone, Copy, Serialize)]
 pub struct RuleDispatch<'a> {
     #[serde(default)]
     pub enabled: &'a [String],
     #[serde(default)]
     pub disabled: &'a [String],
+    #[serde(default)]
+    pub options: &'a Map<String, Value>,
 }
 
 #[derive(Serialize)]
 #[serde(tag = "kind", rename_all = "snake_case")]
 enum HostRequest<'a> {
     Init {
+        protocol_version: u32,
+        schemas: PayloadSchemas,
         scripts: &'a [ScriptInit<'a>],
     },
     RunStage {
@@ -58,62 +96,86 @@ enum HostRequest<'a> {
         payload: Value,
         rules: RuleDispatch<'a>,
     },
+    Describe,
     Shutdown,
 }
 
 #[derive(Deserialize)]
 #[serde(tag = "type", rename_all = "lowercase")]
 enum HostResponse {
-    Ready,
+    Ready {
+        #[serde(default)]
+        protocol_version: Option<u32>,
+        #[serde(default)]
+        capabilities: Vec<String>,
+        #[serde(default)]
+        schemas: HashMap<String, Vec<u32>>,
+    },
     Violations {
         violations: Vec<Violation>,
+        #[serde(default)]
+        timings: Vec<RuleTiming>,
     },
     Error {
         detail: Option<String>,
         script: Option<String>,
     },
+    Rules {
+        rules: Vec<RuleMeta>,
+    },
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct RuleMeta {
+    pub id: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub rationale: Option<String>,
+    #[serde(default)]
+    pub default_severity: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub docs_url: Option<String>,
+    #[serde(default)]
+    pub script: Option<String>,
 }
 
 pub struct StageRunResult {
     pub violations: Vec<Violation>,
     pub response_bytes: usize,
+    pub timings: Vec<RuleTiming>,
+    pub serialize: Duration,
 }
 
-impl PythonHost {
-    pub fn start(cfg: &Config) -> Result<Self, PluginError> {
-        let script_specs = collect_script_specs(cfg);
-        let host_path = resolve_script_path(cfg, "plugins/lib/rule_host.py");
-        let cmd_preview = format_plugin_command(&cfg.plugin.cmd, &cfg.plugin.args, &host_path);
+impl PluginHost {
+    pub fn start(cfg: &Config, host: Option<&str>) -> Result<Self, PluginError> {
+        let script_specs = collect_script_specs(cfg, host);
+        let (program, args) = match host {
+            None => {
+                let mut args = cfg.plugin.args.clone();
+                args.push(resolve_script_path(cfg, "plugins/lib/rule_host.py"));
+                (cfg.plugin.cmd.clone(), args)
+            }
+            Some(name) => {
+                let command = cfg.plugin.hosts.get(name).ok_or_else(|| PluginError::SpawnFailed {
+                    detail: format!("host {name} is not defined under plugin.hosts"),
+                })?;
+                (command.cmd.clone(), command.args.clone())
+            }
+        };
         let runtime = Runtime::new().map_err(|e| PluginError::SpawnFailed { detail: e.to_string() })?;
         let timeout = Duration::from_millis(cfg.defaults.timeout_ms_per_file);
         let snippet_limit = cfg.logging.stderr_snippet_bytes;
         let severity_override = build_severity_override(&cfg.rule);
         let rule_enabled = build_rule_enabled(&cfg.rule);
-        let (child, stdin, stdout, stderr) = runtime.block_on(async {
-            let mut cmd = Command::new(&cfg.plugin.cmd);
-            cmd.args(&cfg.plugin.args)
-                .arg(&host_path)
-                .stdin(Stdio::piped())
-                .stdout(Stdio::piped())
-                .stderr(Stdio::piped());
-            let mut child = cmd.spawn().map_err(|e| PluginError::SpawnFailed {
-                detail: format!("{cmd_preview}: {e}"),
-            })?;
-            let stdin = child.stdin.take().ok_or_else(|| PluginError::IoFailed {
-                detail: "stdin unavailable".to_string(),
-            })?;
-            let stdout = child.stdout.take().ok_or_else(|| PluginError::IoFailed {
-                detail: "stdout unavailable".to_string(),
-            })?;
-            let stderr = child.stderr.take().ok_or_else(|| PluginError::IoFailed {
-                detail: "stderr unavailable".to_string(),
-            })?;
-            Ok((child, stdin, stdout, stderr))
-        })?;
-        let stdout_rx = spawn_stdout(&runtime, stdout);
-        let stderr_buf = spawn_stderr(&runtime, stderr);
+        let (child, stdin, stdout_rx, stderr_buf) = spawn_child(&runtime, &program, &args)?;
         let mut host = Self {
             runtime,
+            program,
+            args,
+            scripts: script_specs,
             child,
             stdin,
             stdout_rx,
@@ -123,11 +185,32 @@ impl PythonHost {
             snippet_limit,
             severity_override,
             rule_enabled,
+            crash_severity: match cfg.plugin.on_rule_crash {
+                RuleCrashPolicy::Error => Severity::Error,
+                RuleCrashPolicy::Warning => Severity::Warning,
+            },
+            capabilities: Capabilities::default(),
         };
-        host.init(&script_specs)?;
+        host.init()?;
         Ok(host)
     }
 
+    pub fn restart(&mut self) -> Result<(), PluginError> {
+        let child = &mut self.child;
+        self.runtime.block_on(async {
+            let _ = child.start_kill();
+            let _ = child.wait().await;
+        });
+        let (child, stdin, stdout_rx, stderr_buf) = spawn_child(&self.runtime, &self.program, &self.args)?;
+        self.child = child;
+        self.stdin = stdin;
+        self.stdout_rx = stdout_rx;
+        self.stderr_buf = stderr_buf;
+        self.stderr_pos = 0;
+        self.capabilities = Capabilities::default();
+        self.init()
+    }
+
     pub fn run_stage(
         &mut self,
         stage: &Stage,
@@ -140,7 +223,9 @@ impl PythonHost {
         log_event(Ev::new(Event::PluginInvoke, &path_s).with_stage(stage_name));
         let t0 = Instant::now();
         let payload_value =
-            serde_json::to_value(&payload).map_err(|e| PluginError::BadJson { detail: e.to_string() })?;
+            serde_json::to_value(payload).map_err(|e| PluginError::BadJson { detail: e.to_string() })?;
+        let serialize = t0.elapsed();
+        let enabled: HashSet<String> = rules.enabled.iter().cloned().collect();
         let req = HostRequest::RunStage {
             stage: stage_name,
             path: input_path,
@@ -149,8 +234,13 @@ impl PythonHost {
         };
         self.send(&req)?;
         let (resp, response_bytes) = self.recv_with_logging(&path_s, stage_name, t0)?;
-        let violations = self.handle_response(resp, &path_s, stage_name, t0)?;
-        let adjusted = self.apply_overrides(violations);
+        let (violations, timings) = self.handle_response(resp, &path_s, stage_name, t0)?;
+        let mut adjusted = self.apply_overrides(violations, &enabled);
+        if !self.capabilities.fixes {
+            for v in &mut adjusted {
+                v.fixes.clear();
+            }
+        }
         self.log_stderr(&path_s, stage_name);
         let elapsed = t0.elapsed().as_millis();
         log_event(
@@ -161,28 +251,86 @@ impl PythonHost {
         Ok(StageRunResult {
             violations: adjusted,
             response_bytes,
+            timings,
+            serialize,
         })
     }
 
-    fn init(&mut self, scripts: &[ScriptSpec]) -> Result<(), PluginError> {
-        let payload: Vec<_> = scripts
+    fn init(&mut self) -> Result<(), PluginError> {
+        let payload: Vec<_> = self
+            .scripts
             .iter()
             .map(|spec| ScriptInit {
                 path: spec.path.as_str(),
                 stages: spec.stages.as_slice(),
                 stage_rules: &spec.stage_rules,
+                options: &spec.options,
             })
             .collect();
-        let req = HostRequest::Init { scripts: &payload };
-        self.send(&req)?;
+        let req = HostRequest::Init {
+            protocol_version: PROTOCOL_VERSION,
+            schemas: PayloadSchemas {
+                cst_ir: CST_IR_SCHEMA,
+                ast: AST_SCHEMA_VERSION,
+            },
+            scripts: &payload,
+        };
+        let data = serde_json::to_vec(&req).map_err(|e| PluginError::BadJson { detail: e.to_string() })?;
+        self.send_bytes(&data)?;
         match self.recv()? {
-            (HostResponse::Ready, _) => Ok(()),
+            (
+                HostResponse::Ready {
+                    protocol_version,
+                    capabilities,
+                    schemas,
+                },
+                _,
+            ) => {
+                if protocol_version != Some(PROTOCOL_VERSION) {
+                    return Err(PluginError::ProtocolMismatch {
+                        host: protocol_version,
+                        core: PROTOCOL_VERSION,
+                    });
+                }
+                for (payload, version) in [("cst_ir", CST_IR_SCHEMA), ("ast", AST_SCHEMA_VERSION)] {
+                    if schemas
+                        .get(payload)
+                        .is_some_and(|supported| !supported.contains(&version))
+                    {
+                        return Err(PluginError::UnsupportedSchema {
+                            payload: payload.to_string(),
+                            version,
+                        });
+                    }
+                }
+                self.capabilities = Capabilities::from_names(&capabilities);
+                Ok(())
+            }
             (HostResponse::Error { detail, .. }, _) => Err(PluginError::ProtocolError {
                 detail: detail.unwrap_or_else(|| "init failed".to_string()),
             }),
             (HostResponse::Violations { .. }, _) => Err(PluginError::ProtocolError {
                 detail: "unexpected violations response during init".to_string(),
             }),
+            (HostResponse::Rules { .. }, _) => Err(PluginError::ProtocolError {
+                detail: "unexpected rules response during init".to_string(),
+            }),
+        }
+    }
+
+    pub fn describe(&mut self) -> Result<Vec<RuleMeta>, PluginError> {
+        if !self.capabilities.metadata {
+            return Ok(Vec::new());
+        }
+        self.send(&HostRequest::Describe)?;
+        match self.recv()? {
+            (HostResponse::Rules { rules }, _) => Ok(rules),
+            (HostResponse::Error { detail, .. }, _) => Err(PluginError::ProtocolError {
+                detail: detail.unwrap_or_else(|| "describe failed".to_string()),
+            }),
+            _ => Err(PluginError::ProtocolError {
+                detail: "unexpected response to describe".to_string(),
+            }),
         }
     }
 
@@ -235,9 +383,9 @@ impl PythonHost {
         path: &str,
         stage: &str,
         start: Instant,
-    ) -> Result<Vec<Violation>, PluginError> {
+    ) -> Result<(Vec<Violation>, Vec<RuleTiming>), PluginError> {
         match resp {
-            HostResponse::Violations { violations } => Ok(violations),
+            HostResponse::Violations { violations, timings } => Ok((violations, timings)),
             HostResponse::Error { detail, script } => {
                 let mut detail = detail.unwrap_or_else(|| "plugin error".to_string());
                 if let Some(script_path) = script.as_deref() {
@@ -252,8 +400,13 @@ impl PythonHost {
                 self.log_stderr(path, stage);
                 Err(PluginError::ProtocolError { detail })
             }
-            HostResponse::Ready => {
-                let detail = "unexpected ready response".to_string();
+            resp @ (HostResponse::Ready { .. } | HostResponse::Rules { .. }) => {
+                let kind = if matches!(resp, HostResponse::Ready { .. }) {
+                    "ready"
+                } else {
+                    "rules"
+                };
+                let detail = format!("unexpected {kind} response");
                 let elapsed = start.elapsed().as_millis();
                 let mut ev = Ev::new(Event::PluginError, path)
                     .with_stage(stage)
@@ -268,10 +421,14 @@ impl PythonHost {
 
     fn send(&mut self, req: &HostRequest<'_>) -> Result<(), PluginError> {
         let data = serde_json::to_vec(req).map_err(|e| PluginError::BadJson { detail: e.to_string() })?;
+        self.send_bytes(&data)
+    }
+
+    fn send_bytes(&mut self, data: &[u8]) -> Result<(), PluginError> {
         let stdin = &mut self.stdin;
         self.runtime
             .block_on(async {
-                stdin.write_all(&data).await?;
+                stdin.write_all(data).await?;
                 stdin.write_all(b"\n").await?;
                 stdin.flush().await
             })
@@ -309,15 +466,19 @@ impl PythonHost {
         })
     }
 
-    fn apply_overrides(&self, violations: Vec<Violation>) -> Vec<Violation> {
+    fn apply_overrides(&self, violations: Vec<Violation>, enabled: &HashSet<String>) -> Vec<Violation> {
         let mut out = Vec::with_capacity(violations.len());
         for mut v in violations {
-            if matches!(self.rule_enabled.get(&v.rule_id), Some(false)) {
+            let known = self.rule_enabled.contains_key(&v.rule_id);
+            if known && !enabled.contains(&v.rule_id) {
                 continue;
             }
             if let Some(sev) = self.severity_override.get(&v.rule_id) {
                 v.severity = *sev;
             }
+            if v.rule_id == RULE_CRASHED {
+                v.severity = self.crash_severity;
+            }
             out.push(v);
         }
         out
@@ -349,7 +510,7 @@ impl PythonHost {
     }
 }
 
-impl Drop for PythonHost {
+impl Drop for PluginHost {
     fn drop(&mut self) {
         let _ = self.send(&HostRequest::Shutdown);
         let child = &mut self.child;
@@ -360,6 +521,35 @@ impl Drop for PythonHost {
     }
 }
 
+type Spawned = (Child, ChildStdin, UnboundedReceiver<String>, Arc<Mutex<Vec<u8>>>);
+
+fn spawn_child(runtime: &Runtime, program: &str, args: &[String]) -> Result<Spawned, PluginError> {
+    let cmd_preview = format_plugin_command(program, args);
+    let (child, stdin, stdout, stderr) = runtime.block_on(async {
+        let mut cmd = Command::new(program);
+        cmd.args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        let mut child = cmd.spawn().map_err(|e| PluginError::SpawnFailed {
+            detail: format!("{cmd_preview}: {e}"),
+        })?;
+        let stdin = child.stdin.take().ok_or_else(|| PluginError::IoFailed {
+            detail: "stdin unavailable".to_string(),
+        })?;
+        let stdout = child.stdout.take().ok_or_else(|| PluginError::IoFailed {
+            detail: "stdout unavailable".to_string(),
+        })?;
+        let stderr = child.stderr.take().ok_or_else(|| PluginError::IoFailed {
+            detail: "stderr unavailable".to_string(),
+        })?;
+        Ok::<_, PluginError>((child, stdin, stdout, stderr))
+    })?;
+    let stdout_rx = spawn_stdout(runtime, stdout);
+    let stderr_buf = spawn_stderr(runtime, stderr);
+    Ok((child, stdin, stdout_rx, stderr_buf))
+}
+
 fn spawn_stdout(runtime: &Runtime, stdout: ChildStdout) -> UnboundedReceiver<String> {
     let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
     runtime.spawn(async move {
@@ -431,7 +621,7 @@ fn build_rule_enabled(rules: &[crate::config::RuleConfig]) -> HashMap<String, bo
     map
 }
 
-fn parse_severity(s: &str) -> Option<Severity> {
+pub(crate) fn parse_severity(s: &str) -> Option<Severity> {
     match s {
         "error" => Some(Severity::Error),
         "warning" => Some(Severity::Warning),
@@ -440,11 +630,10 @@ fn parse_severity(s: &str) -> Option<Severity> {
     }
 }
 
-fn format_plugin_command(cmd: &str, args: &[String], host_path: &str) -> String {
-    let mut parts = Vec::with_capacity(args.len() + 2);
+fn format_plugin_command(cmd: &str, args: &[String]) -> String {
+    let mut parts = Vec::with_capacity(args.len() + 1);
     parts.push(cmd.to_string());
     parts.extend(args.iter().cloned());
-    parts.push(host_path.to_string());
     let mut out = String::new();
     for (i, part) in parts.iter().enumerate() {
         if i > 0 {
@@ -0,0 +1,204 @@
+use crate::config::{Config, RuleConfig};
+use crate::core::errors::PluginError;
+use crate::core::payload::StagePayload;
+use crate::diag::event::{Ev, Event};
+use crate::diag::logging::log_event;
+use crate::plugin::client::{PluginHost, RuleDispatch, RuleMeta, StageRunResult};
+use crate::types::Stage;
+use std::collections::HashSet;
+use std::path::Path;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::warn;
+
+pub struct HostPool {
+    hosts: Vec<PooledHost>,
+    restarts: Arc<RestartBudget>,
+    crashed: Option<Crash>,
+}
+
+struct Crash {
+    host: usize,
+    stage: Stage,
+    detail: String,
+}
+
+pub struct RestartBudget {
+    remaining: AtomicU32,
+}
+
+impl RestartBudget {
+    pub fn new(max: u32) -> Self {
+        Self {
+            remaining: AtomicU32::new(max),
+        }
+    }
+
+    fn take(&self) -> bool {
+        self.remaining
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+            .is_ok()
+    }
+}
+
+struct PooledHost {
+    name: Option<String>,
+    rules: HashSet<String>,
+    host: PluginHost,
+}
+
+impl HostPool {
+    /// Starts one host per group that has a rule enabled in the config or by an override.
+    pub fn start(cfg: &Config) -> Result<Self, PluginError> {
+        Self::start_matching(cfg, |rule| rule.enabled || enabled_by_override(cfg, &rule.id))
+    }
+
+    /// Starts a host for every plugin rule, enabled or not, so all of them can be described.
+    pub fn start_all(cfg: &Config) -> Result<Self, PluginError> {
+        Self::start_matching(cfg, |_| true)
+    }
+
+    fn start_matching(cfg: &Config, active: impl Fn(&RuleConfig) -> bool) -> Result<Self, PluginError> {
+        let mut groups: Vec<(Option<&str>, HashSet<String>, bool)> = Vec::new();
+        for rule in cfg.rule.iter().filter(|r| !r.native) {
+            let name = rule.host.as_deref();
+            match groups.iter_mut().find(|(g, _, _)| *g == name) {
+                Some((_, ids, any_active)) => {
+                    ids.insert(rule.id.clone());
+                    *any_active |= active(rule);
+                }
+                None => groups.push((name, HashSet::from([rule.id.clone()]), active(rule))),
+            }
+        }
+        let mut hosts = Vec::with_capacity(groups.len());
+        for (name, rules, _) in groups.into_iter().filter(|(_, _, any_active)| *any_active) {
+            hosts.push(PooledHost {
+                name: name.map(str::to_string),
+                rules,
+                host: PluginHost::start(cfg, name)?,
+            });
+        }
+        Ok(Self {
+            hosts,
+            restarts: Arc::new(RestartBudget::new(cfg.plugin.max_restarts)),
+            crashed: None,
+        })
+    }
+
+    pub fn with_restart_budget(mut self, restarts: Arc<RestartBudget>) -> Self {
+        self.restarts = restarts;
+        self
+    }
+
+    pub fn run_stage(
+        &mut self,
+        stage: &Stage,
+        input_path: &Path,
+        payload: StagePayload<'_>,
+        rules: RuleDispatch<'_>,
+    ) -> Result<StageRunResult, PluginError> {
+        self.crashed = None;
+        if self.hosts.len() == 1 {
+            return self.run_on(0, stage, input_path, payload, rules);
+        }
+        let mut out = StageRunResult {
+            violations: Vec::new(),
+            response_bytes: 0,
+            timings: Vec::new(),
+            serialize: Duration::ZERO,
+        };
+        for index in 0..self.hosts.len() {
+            let pooled = &self.hosts[index];
+            let enabled: Vec<String> = rules
+                .enabled
+                .iter()
+                .filter(|id| pooled.rules.contains(*id))
+                .cloned()
+                .collect();
+            if enabled.is_empty() {
+                continue;
+            }
+            let disabled: Vec<String> = rules
+                .disabled
+                .iter()
+                .filter(|id| pooled.rules.contains(*id))
+                .cloned()
+                .collect();
+            let dispatch = RuleDispatch {
+                enabled: &enabled,
+                disabled: &disabled,
+                options: rules.options,
+            };
+            let result = self.run_on(index, stage, input_path, payload, dispatch)?;
+            out.violations.extend(result.violations);
+            out.response_bytes += result.response_bytes;
+            out.timings.extend(result.timings);
+            out.serialize += result.serialize;
+        }
+        Ok(out)
+    }
+
+    pub fn restart_crashed(&mut self, input_path: &Path) -> Result<bool, PluginError> {
+        let Some(crash) = self.crashed.take() else {
+            return Ok(false);
+        };
+        if !self.restarts.take() {
+            return Ok(false);
+        }
+        let pooled = &mut self.hosts[crash.host];
+        let path = input_path.to_string_lossy();
+        warn!(
+            "{}: restarting plugin host{} after {}",
+            path,
+            pooled.name.as_deref().map(|n| format!(" {n}")).unwrap_or_default(),
+            crash.detail
+        );
+        log_event(
+            Ev::new(Event::PluginRestart, &path)
+                .with_stage(crash.stage.as_str())
+                .with_message(&crash.detail),
+        );
+        pooled.host.restart()?;
+        Ok(true)
+    }
+
+    fn run_on(
+        &mut self,
+        index: usize,
+        stage: &Stage,
+        input_path: &Path,
+        payload: StagePayload<'_>,
+        rules: RuleDispatch<'_>,
+    ) -> Result<StageRunResult, PluginError> {
+        let result = self.hosts[index].host.run_stage(stage, input_path, payload, rules);
+        if let Err(e @ (PluginError::Timeout { .. } | PluginError::ExitCode { .. } | PluginError::IoFailed { .. })) =
+            &result
+        {
+            self.crashed = Some(Crash {
+                host: index,
+                stage: *stage,
+                detail: e.to_string(),
+            });
+        }
+        result
+    }
+
+    pub fn describe(&mut self) -> Result<Vec<RuleMeta>, PluginError> {
+        let mut out = Vec::new();
+        for pooled in &mut self.hosts {
+            match pooled.host.describe() {
+                Ok(rules) => out.extend(rules),
+                Err(PluginError::ProtocolError { .. }) if pooled.name.is_some() => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(out)
+    }
+}
+
+fn enabled_by_override(cfg: &Config, id: &str) -> bool {
+    cfg.overrides
+        .iter()
+        .any(|o| o.rule.iter().any(|r| r.id == id && r.enabled == Some(true)))
+}
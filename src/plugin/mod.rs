@@ -1,2 +1,6 @@
+pub mod catalog;
 pub mod client;
-pub use client::PythonHost;
+pub mod hosts;
+pub use catalog::{describe_rules, rule_examples, RuleExample, RuleInfo};
+pub use client::PluginHost;
+pub use hosts::{HostPool, RestartBudget};
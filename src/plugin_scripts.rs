@@ -1,4 +1,5 @@
 use crate::config::{plugin_search_paths, Config};
+use serde_json::{Map, Value};
 use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::path::Path;
 
@@ -36,18 +37,20 @@ fn iter_candidates(cfg: &Config, rel: &str) -> Vec<std::path::PathBuf> {
 struct ScriptSpecBuilder {
     stages: BTreeSet<String>,
     stage_rules: BTreeMap<String, Vec<String>>,
+    options: BTreeMap<String, Map<String, Value>>,
 }
 
 pub struct ScriptSpec {
     pub path: String,
     pub stages: Vec<String>,
     pub stage_rules: BTreeMap<String, Vec<String>>,
+    pub options: BTreeMap<String, Map<String, Value>>,
 }
 
-pub fn collect_script_specs(cfg: &Config) -> Vec<ScriptSpec> {
+pub fn collect_script_specs(cfg: &Config, host: Option<&str>) -> Vec<ScriptSpec> {
     let mut order: Vec<String> = Vec::new();
     let mut specs: HashMap<String, ScriptSpecBuilder> = HashMap::new();
-    for rule in &cfg.rule {
+    for rule in cfg.rule.iter().filter(|r| !r.native && r.host.as_deref() == host) {
         let path = resolve_script_path(cfg, &rule.script);
         let stage = rule.stage();
         let entry = specs.entry(path.clone()).or_insert_with(|| {
@@ -60,6 +63,7 @@ pub fn collect_script_specs(cfg: &Config) -> Vec<ScriptSpec> {
             .entry(stage.as_str().to_string())
             .or_default()
             .push(rule.id.clone());
+        entry.options.insert(rule.id.clone(), rule.options.clone());
     }
     order
         .into_iter()
@@ -74,6 +78,7 @@ pub fn collect_script_specs(cfg: &Config) -> Vec<ScriptSpec> {
                 path,
                 stages: builder.stages.into_iter().collect(),
                 stage_rules,
+                options: builder.options,
             }
         })
         .collect()
@@ -460,18 +460,16 @@ fn scan_assignment_at(text: &str, lhs_start: usize) -> Option<(AssignOp, String,
             ']' => depth_brack = depth_brack.saturating_sub(1),
             '{' => depth_brace += 1,
             '}' => depth_brace = depth_brace.saturating_sub(1),
-            '<' => {
-                if i + 1 < bytes.len()
-                    && bytes[i + 1] as char == '='
-                    && depth_paren == 0
-                    && depth_brack == 0
-                    && depth_brace == 0
-                {
-                    let lhs = text[lhs_start..i].trim().to_string();
-                    let (rhs_start, rhs_end) = find_rhs_range(text, i + 2)?;
-                    let rhs = text[rhs_start..rhs_end].trim().to_string();
-                    return Some((AssignOp::Nonblocking, lhs, rhs, rhs_start, rhs_end));
-                }
+            '<' if i + 1 < bytes.len()
+                && bytes[i + 1] as char == '='
+                && depth_paren == 0
+                && depth_brack == 0
+                && depth_brace == 0 =>
+            {
+                let lhs = text[lhs_start..i].trim().to_string();
+                let (rhs_start, rhs_end) = find_rhs_range(text, i + 2)?;
+                let rhs = text[rhs_start..rhs_end].trim().to_string();
+                return Some((AssignOp::Nonblocking, lhs, rhs, rhs_start, rhs_end));
             }
             '=' => {
                 let prev = if i > 0 { bytes[i - 1] as char } else { '\0' };
@@ -510,10 +508,8 @@ fn find_rhs_range(text: &str, rhs_start: usize) -> Option<(usize, usize)> {
             ']' => depth_brack = depth_brack.saturating_sub(1),
             '{' => depth_brace += 1,
             '}' => depth_brace = depth_brace.saturating_sub(1),
-            ';' => {
-                if depth_paren == 0 && depth_brack == 0 && depth_brace == 0 {
-                    return Some((rhs_start, i));
-                }
+            ';' if depth_paren == 0 && depth_brack == 0 && depth_brace == 0 => {
+                return Some((rhs_start, i));
             }
             _ => {}
         }
@@ -3,6 +3,8 @@ use serde_json::{Map as JsonMap, Value as JsonValue};
 use std::collections::HashMap;
 use sv_parser::{Locate, NodeEvent, RefNode, SyntaxTree};
 
+pub const CST_IR_SCHEMA: u32 = 2;
+
 #[derive(Clone, Debug, Serialize)]
 pub struct CstIncludeFlags {
     pub text: bool,
@@ -147,7 +149,7 @@ impl CstBuilder {
         this.fill_directives_from_text();
         this.apply_directive_nesting();
         CstIr {
-            schema: 2,
+            schema: CST_IR_SCHEMA,
             format: "json",
             sv_parser: this.sv_parser,
             file: this.file,
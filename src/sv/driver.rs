@@ -4,9 +4,11 @@ use crate::diag::logging::log_event;
 use crate::sv::collect::{analyze_symbols, collect_all};
 use crate::sv::cst_ir::build_cst_ir;
 pub use crate::sv::model::SvParserCfg;
-use crate::sv::model::{AstSummary, DefineInfo, ParseArtifacts};
+use crate::sv::model::{AstSummary, DefineInfo, ParseArtifacts, ParseTimings};
+use crate::sv::origin::PpOrigins;
 use crate::sv::preprocess::ParserInputs;
 use crate::sv::source::SourceCache;
+use crate::textutil::content_hash;
 use std::path::Path;
 use std::time::Instant;
 
@@ -35,14 +37,18 @@ impl SvDriver {
         let t0 = Instant::now();
         let preprocess = self.inputs.preprocess(input_path, raw_text);
         let pp_text = preprocess.text.clone();
-        let elapsed_pp = t0.elapsed().as_millis();
-        log_event(Ev::new(Event::ParsePreprocessDone, &path_s).with_duration_ms(elapsed_pp));
+        let pp_origins = match &preprocess.block {
+            Some(block) => PpOrigins::from_preprocessed(block, input_path, raw_text),
+            None => PpOrigins::identity(raw_text),
+        };
+        let elapsed_pp = t0.elapsed();
+        log_event(Ev::new(Event::ParsePreprocessDone, &path_s).with_duration_ms(elapsed_pp.as_millis()));
 
         log_event(Ev::new(Event::ParseParseStart, &path_s));
         let t1 = Instant::now();
         let parse_out = self.inputs.parse(input_path, preprocess);
-        let elapsed_parse = t1.elapsed().as_millis();
-        log_event(Ev::new(Event::ParseParseDone, &path_s).with_duration_ms(elapsed_parse));
+        let elapsed_parse = t1.elapsed();
+        log_event(Ev::new(Event::ParseParseDone, &path_s).with_duration_ms(elapsed_parse.as_millis()));
 
         let tree = parse_out.syntax_tree.as_ref().ok_or_else(|| ParseError::ParseFailed {
             detail: format!("{}: parser produced no syntax tree", path_s),
@@ -65,7 +71,9 @@ impl SvDriver {
 
         let line_starts = line_starts(&pp_text);
         let cst_ir = if has_cst {
-            Some(build_cst_ir(tree, &path_s, "", &line_starts, &pp_text))
+            let mut ir = build_cst_ir(tree, &path_s, "", &line_starts, &pp_text);
+            ir.hash = content_hash(raw_owned.as_bytes());
+            Some(ir)
         } else {
             None
         };
@@ -78,6 +86,11 @@ impl SvDriver {
             has_cst,
             ast,
             cst_ir,
+            timings: ParseTimings {
+                preprocess: elapsed_pp,
+                parse: elapsed_parse,
+            },
+            pp_origins,
         })
     }
 }
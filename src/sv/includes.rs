@@ -0,0 +1,77 @@
+use regex::Regex;
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+pub struct IncludeRef {
+    pub name: String,
+    pub path: Option<PathBuf>,
+}
+
+pub fn resolve_includes(path: &Path, text: &str, include_paths: &[String]) -> Vec<IncludeRef> {
+    let mut out = Vec::new();
+    let mut seen: BTreeSet<PathBuf> = BTreeSet::new();
+    let mut pending: Vec<(PathBuf, String)> = vec![(path.to_path_buf(), text.to_string())];
+    while let Some((from, source)) = pending.pop() {
+        for name in include_names(&source) {
+            let found = resolve_include(&from, &name, include_paths);
+            if let Some(found) = &found {
+                if !seen.insert(found.clone()) {
+                    continue;
+                }
+                if let Ok(nested) = fs::read_to_string(found) {
+                    pending.push((found.clone(), nested));
+                }
+            }
+            out.push(IncludeRef { name, path: found });
+        }
+    }
+    out
+}
+
+fn include_names(text: &str) -> Vec<String> {
+    static INCLUDE_RE: OnceLock<Regex> = OnceLock::new();
+    let re = INCLUDE_RE.get_or_init(|| Regex::new(r#"`include\s*(?:"([^"]+)"|<([^>]+)>)"#).expect("include regex"));
+    re.captures_iter(text)
+        .filter_map(|c| c.get(1).or_else(|| c.get(2)))
+        .map(|m| m.as_str().to_string())
+        .collect()
+}
+
+fn resolve_include(from: &Path, name: &str, include_paths: &[String]) -> Option<PathBuf> {
+    let local = from.parent().map(|dir| dir.join(name));
+    local
+        .into_iter()
+        .chain(include_paths.iter().map(|dir| Path::new(dir).join(name)))
+        .find(|candidate| candidate.is_file())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn include_names_cover_quoted_and_angled_forms() {
+        let text = "`include \"defs.svh\"\n`include <pkg/types.svh>\n// `define X\n";
+        assert_eq!(include_names(text), ["defs.svh", "pkg/types.svh"]);
+    }
+
+    #[test]
+    fn nested_includes_resolve_once() {
+        let tmp = tempfile::tempdir().unwrap();
+        let inc = tmp.path().join("inc");
+        fs::create_dir(&inc).unwrap();
+        fs::write(inc.join("a.svh"), "`include \"b.svh\"\n").unwrap();
+        fs::write(inc.join("b.svh"), "`include \"a.svh\"\n").unwrap();
+        let top = tmp.path().join("top.sv");
+        let refs = resolve_includes(
+            &top,
+            "`include \"a.svh\"\n`include \"gone.svh\"\n",
+            &[inc.to_string_lossy().into_owned()],
+        );
+        let names: Vec<&str> = refs.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, ["a.svh", "gone.svh", "b.svh"]);
+        assert!(refs[1].path.is_none());
+    }
+}
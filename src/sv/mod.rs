@@ -1,6 +1,8 @@
 mod collect;
 pub mod cst_ir;
 pub mod driver;
+pub mod includes;
 pub mod model;
+pub mod origin;
 mod preprocess;
 pub(crate) mod source;
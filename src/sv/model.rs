@@ -1,6 +1,10 @@
 use crate::sv::cst_ir::CstIr;
+use crate::sv::origin::PpOrigins;
 use crate::types::Location;
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+pub const AST_SCHEMA_VERSION: u32 = 1;
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SvParserCfg {
@@ -148,12 +152,18 @@ impl Default for AstSummary {
             assigns: Vec::new(),
             ports: Vec::new(),
             pp_text: None,
-            schema_version: 1,
+            schema_version: AST_SCHEMA_VERSION,
             scopes: Vec::new(),
         }
     }
 }
 
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ParseTimings {
+    pub preprocess: Duration,
+    pub parse: Duration,
+}
+
 #[derive(Clone, Debug, Serialize)]
 pub struct ParseArtifacts {
     pub raw_text: String,
@@ -164,4 +174,8 @@ pub struct ParseArtifacts {
     pub ast: AstSummary,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cst_ir: Option<CstIr>,
+    #[serde(skip)]
+    pub timings: ParseTimings,
+    #[serde(skip)]
+    pub pp_origins: PpOrigins,
 }
@@ -0,0 +1,96 @@
+use std::path::Path;
+use sv_parser::PreprocessedText;
+
+/// Maps byte offsets in the preprocessed text back to the normalized input text.
+#[derive(Clone, Debug, Default)]
+pub struct PpOrigins {
+    spans: Vec<OriginSpan>,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct OriginSpan {
+    pp: usize,
+    src: usize,
+    len: usize,
+}
+
+impl PpOrigins {
+    pub(crate) fn from_preprocessed(block: &PreprocessedText, path: &Path, raw: &str) -> Self {
+        let text = block.text();
+        Self::build(
+            raw,
+            (0..text.len()).map(|pos| match block.origin(pos) {
+                Some((origin, offset)) if origin == path => Some(offset),
+                _ => None,
+            }),
+        )
+    }
+
+    pub(crate) fn identity(raw: &str) -> Self {
+        Self::build(raw, (0..raw.len()).map(Some))
+    }
+
+    fn build(raw: &str, raw_offsets: impl Iterator<Item = Option<usize>>) -> Self {
+        let removed = removed_bytes(raw);
+        let mut spans: Vec<OriginSpan> = Vec::new();
+        for (pp, raw_offset) in raw_offsets.enumerate() {
+            let Some(raw_offset) = raw_offset else { continue };
+            if removed.binary_search(&raw_offset).is_ok() {
+                continue;
+            }
+            let src = raw_offset - removed.partition_point(|&r| r < raw_offset);
+            match spans.last_mut() {
+                Some(span) if span.pp + span.len == pp && span.src + span.len == src => span.len += 1,
+                _ => spans.push(OriginSpan { pp, src, len: 1 }),
+            }
+        }
+        Self { spans }
+    }
+
+    /// Maps a pp range that was copied verbatim from the input; `None` if it was not.
+    pub fn range(&self, start: usize, end: usize) -> Option<(usize, usize)> {
+        let idx = self.spans.partition_point(|s| s.pp <= start).checked_sub(1)?;
+        let span = self.spans[idx];
+        if end < start || end > span.pp + span.len {
+            return None;
+        }
+        Some((span.src + (start - span.pp), span.src + (end - span.pp)))
+    }
+
+    /// Maps a pp offset to the input, clamping text without an input origin to the
+    /// end of the nearest preceding copied span.
+    pub fn nearest(&self, pos: usize) -> Option<usize> {
+        let idx = self.spans.partition_point(|s| s.pp <= pos).checked_sub(1)?;
+        let span = self.spans[idx];
+        Some(span.src + (pos - span.pp).min(span.len))
+    }
+}
+
+fn removed_bytes(raw: &str) -> Vec<usize> {
+    let bytes = raw.as_bytes();
+    let mut removed: Vec<usize> = if bytes.starts_with(&[0xEF, 0xBB, 0xBF]) {
+        vec![0, 1, 2]
+    } else {
+        Vec::new()
+    };
+    for (i, pair) in bytes.windows(2).enumerate() {
+        if pair == b"\r\n" {
+            removed.push(i);
+        }
+    }
+    removed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identity_skips_bom_and_carriage_returns() {
+        let origins = PpOrigins::identity("\u{feff}a\r\nbc\n");
+        assert_eq!(origins.range(3, 4), Some((0, 1)));
+        assert_eq!(origins.range(6, 8), Some((2, 4)));
+        assert_eq!(origins.range(3, 6), None);
+        assert_eq!(origins.nearest(4), Some(1));
+    }
+}
@@ -1,7 +1,7 @@
 use crate::sv::model::SvParserCfg;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
-use sv_parser::{parse_sv_pp, parse_sv_str, preprocess, Define, DefineText, PreprocessedText, SyntaxTree};
+use sv_parser::{parse_sv_pp, parse_sv_str, preprocess_str, Define, DefineText, PreprocessedText, SyntaxTree};
 
 pub(crate) type DefineMap = HashMap<String, Option<Define>>;
 
@@ -44,12 +44,15 @@ impl ParserInputs {
     }
 
     pub fn preprocess(&self, path: &Path, raw_text: &str) -> PreprocessResult {
-        match preprocess(
+        match preprocess_str(
+            raw_text,
             path,
             &self.pre_defines,
             &self.include_paths,
-            self.strip_comments,
             self.ignore_include,
+            self.strip_comments,
+            0,
+            0,
         ) {
             Ok((block, defines)) => PreprocessResult {
                 text: block.text().to_owned(),
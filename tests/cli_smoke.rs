@@ -116,3 +116,1234 @@ fn detects_module_instantiations_includes() {
         &["include_child.sv", "vars_not_left_unused"],
     );
 }
+
+#[test]
+fn ndjson_format_emits_violation_objects() {
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("sv-mint"));
+    cmd.args(["--only", "module_names_lower_snake", "--format", "ndjson"]);
+    cmd.arg("fixtures/rules/module_names_lower_snake/bad.sv");
+    let out = cmd.output().expect("failed to run sv-mint");
+    assert_eq!(out.status.code(), Some(2));
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    let lines: Vec<serde_json::Value> = stdout
+        .lines()
+        .map(|l| serde_json::from_str(l).expect("ndjson line"))
+        .collect();
+    assert!(!lines.is_empty());
+    for v in &lines {
+        assert_eq!(v["rule_id"], "module_names_lower_snake");
+        assert!(v["location"]["end_line"].is_u64());
+        assert!(v["location"]["file"].is_string());
+    }
+}
+
+#[test]
+fn json_format_emits_single_document() {
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("sv-mint"));
+    cmd.args(["--only", "module_names_lower_snake", "--format", "json"]);
+    cmd.arg("fixtures/rules/module_names_lower_snake/bad.sv");
+    cmd.arg("fixtures/rules/module_names_lower_snake/good.sv");
+    let out = cmd.output().expect("failed to run sv-mint");
+    assert_eq!(out.status.code(), Some(2));
+    let doc: serde_json::Value = serde_json::from_slice(&out.stdout).expect("json document");
+    let files = doc["files"].as_array().expect("files array");
+    assert_eq!(files.len(), 2);
+    assert_eq!(doc["summary"]["files"], 2);
+    assert!(doc["summary"]["violations"].as_u64().unwrap() > 0);
+}
+
+#[test]
+fn inline_suppressions_filter_and_report_unused() {
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("sv-mint"));
+    cmd.args(["--only", "module_names_lower_snake", "fixtures/cli/suppressed.sv"]);
+    let out = cmd.output().expect("failed to run sv-mint");
+    assert_eq!(out.status.code(), Some(2));
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(!stdout.contains("MyModule"), "stdout:\n{}", stdout);
+    assert!(!stdout.contains("OtherModule"), "stdout:\n{}", stdout);
+    assert!(
+        stdout.contains("Loud_Module must use lower_snake_case"),
+        "stdout:\n{}",
+        stdout
+    );
+    assert!(
+        stdout.contains(":8:1: [warning] sys.suppression.unused"),
+        "stdout:\n{}",
+        stdout
+    );
+}
+
+#[test]
+fn suppressions_match_source_lines_after_preprocessing() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let input = tmp.path().join("pp.sv");
+    std::fs::write(
+        &input,
+        "`define WIDTH 8\n`ifdef NOT_DEFINED\nwire unused_a;\nwire unused_b;\n`endif\nmodule pp;\n  logic a, b, c;\n  // sv-mint: disable-next-line=sensitivity_list_uses_commas\n  always @(a or b) c = a;\n  always @(a or b) c = b;\nendmodule\n",
+    )
+    .unwrap();
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("sv-mint"));
+    cmd.args(["--no-cache", "--only", "sensitivity_list_uses_commas"])
+        .arg(&input);
+    let out = cmd.output().expect("failed to run sv-mint");
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert_eq!(out.status.code(), Some(2), "stdout:\n{}", stdout);
+    assert!(
+        stdout.contains("pp.sv:10:17: [warning] sensitivity_list_uses_commas"),
+        "stdout:\n{}",
+        stdout
+    );
+    assert!(!stdout.contains("pp.sv:9:"), "stdout:\n{}", stdout);
+    assert!(!stdout.contains("sys.suppression.unused"), "stdout:\n{}", stdout);
+}
+
+#[test]
+fn baseline_grandfathers_existing_violations() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let baseline = tmp.path().join("baseline.json");
+    let input = "fixtures/rules/module_names_lower_snake/bad.sv";
+    let mut write = Command::new(assert_cmd::cargo::cargo_bin!("sv-mint"));
+    write.args(["--only", "module_names_lower_snake", "--write-baseline"]);
+    write.arg(&baseline).arg(input);
+    let out = write.output().expect("failed to run sv-mint");
+    assert_eq!(out.status.code(), Some(0));
+    assert!(out.stdout.is_empty());
+    let text = std::fs::read_to_string(&baseline).expect("baseline written");
+    assert!(text.contains("module_names_lower_snake"));
+    assert!(text.contains(&format!("\"file\": \"{}\"", input)), "{}", text);
+
+    let absolute = std::env::current_dir().unwrap().join(input);
+    let spellings = [
+        std::path::PathBuf::from(input),
+        std::path::PathBuf::from(format!("./{}", input)),
+        absolute,
+    ];
+    for path in spellings {
+        let mut check = Command::new(assert_cmd::cargo::cargo_bin!("sv-mint"));
+        check.args(["--only", "module_names_lower_snake", "--baseline"]);
+        check.arg(&baseline).arg(&path);
+        let out = check.output().expect("failed to run sv-mint");
+        let stdout = String::from_utf8_lossy(&out.stdout);
+        assert_eq!(out.status.code(), Some(0), "{}: stdout:\n{}", path.display(), stdout);
+        assert!(stdout.is_empty());
+    }
+}
+
+#[test]
+fn baseline_keys_are_relative_to_each_config_root() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let root = tmp.path();
+    for (repo, sub) in [("a", "rtl"), ("b", "src")] {
+        let dir = root.join(repo);
+        std::fs::create_dir_all(dir.join(".git")).unwrap();
+        std::fs::create_dir_all(dir.join(sub)).unwrap();
+        std::fs::write(
+            dir.join("sv-mint.toml"),
+            "[[rule]]\nid = \"module_names_lower_snake\"\nnative = true\n",
+        )
+        .unwrap();
+        std::fs::write(dir.join(sub).join("top.sv"), "module BadName;\nendmodule\n").unwrap();
+    }
+    let baseline = root.join("baseline.json");
+    let mut write = Command::new(assert_cmd::cargo::cargo_bin!("sv-mint"));
+    write.current_dir(root).args(["--no-cache", "--write-baseline"]);
+    write.arg(&baseline).args(["a", "b"]);
+    let out = write.output().expect("failed to run sv-mint");
+    assert_eq!(
+        out.status.code(),
+        Some(0),
+        "stderr:\n{}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    let text = std::fs::read_to_string(&baseline).expect("baseline written");
+    assert!(text.contains("\"file\": \"rtl/top.sv\""), "{}", text);
+    assert!(text.contains("\"file\": \"src/top.sv\""), "{}", text);
+
+    let mut check = Command::new(assert_cmd::cargo::cargo_bin!("sv-mint"));
+    check.current_dir(root.join("b/src")).args(["--no-cache", "--baseline"]);
+    check.arg(&baseline).args(["top.sv", "../../a/rtl/top.sv"]);
+    let out = check.output().expect("failed to run sv-mint");
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert_eq!(out.status.code(), Some(0), "stdout:\n{}", stdout);
+}
+
+#[test]
+fn fix_rewrites_files_and_dry_run_prints_diff() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let input = tmp.path().join("bad.sv");
+    let original = std::fs::read_to_string("fixtures/rules/sensitivity_list_uses_commas/bad.sv").expect("read fixture");
+    std::fs::write(&input, &original).expect("write input");
+
+    let mut dry = Command::new(assert_cmd::cargo::cargo_bin!("sv-mint"));
+    dry.args(["--only", "sensitivity_list_uses_commas", "--fix-dry-run"])
+        .arg(&input);
+    let out = dry.output().expect("failed to run sv-mint");
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert_eq!(out.status.code(), Some(2), "stdout:\n{}", stdout);
+    assert!(stdout.contains("+++ "), "stdout:\n{}", stdout);
+    assert!(stdout.contains("-  always_ff @(posedge clk_i or negedge rst_ni)"));
+    assert_eq!(std::fs::read_to_string(&input).unwrap(), original);
+
+    let mut json = Command::new(assert_cmd::cargo::cargo_bin!("sv-mint"));
+    json.args([
+        "--only",
+        "sensitivity_list_uses_commas",
+        "--fix-dry-run",
+        "--format",
+        "json",
+    ])
+    .arg(&input);
+    let out = json.output().expect("failed to run sv-mint");
+    assert_eq!(out.status.code(), Some(3));
+    assert!(out.stdout.is_empty());
+    assert!(String::from_utf8_lossy(&out.stderr).contains("--fix-dry-run only supports text output"));
+
+    let mut fix = Command::new(assert_cmd::cargo::cargo_bin!("sv-mint"));
+    fix.args(["--only", "sensitivity_list_uses_commas", "--fix"])
+        .arg(&input);
+    let out = fix.output().expect("failed to run sv-mint");
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert_eq!(out.status.code(), Some(0), "stdout:\n{}", stdout);
+    let fixed = std::fs::read_to_string(&input).unwrap();
+    assert!(fixed.contains("@(posedge clk_i, negedge rst_ni)"));
+    assert!(!fixed.contains(" or "));
+}
+
+#[test]
+fn fix_maps_edits_through_the_preprocessor() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let input = tmp.path().join("pp.sv");
+    let original = "`define WIDTH 8\n`ifdef NOT_DEFINED\nwire unused;\n`endif\nmodule pp;\n  logic a, b, c;\n  always @(a or b) c = a;\nendmodule\n";
+    std::fs::write(&input, original).unwrap();
+
+    let mut fix = Command::new(assert_cmd::cargo::cargo_bin!("sv-mint"));
+    fix.args(["--no-cache", "--only", "sensitivity_list_uses_commas", "--fix"])
+        .arg(&input);
+    let out = fix.output().expect("failed to run sv-mint");
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert_eq!(out.status.code(), Some(0), "stdout:\n{}", stdout);
+    assert_eq!(
+        std::fs::read_to_string(&input).unwrap(),
+        original.replace("@(a or b)", "@(a, b)")
+    );
+}
+
+#[test]
+fn directory_inputs_are_walked() {
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("sv-mint"));
+    cmd.args([
+        "--only",
+        "module_names_lower_snake",
+        "fixtures/rules/module_names_lower_snake",
+    ]);
+    let out = cmd.output().expect("failed to run sv-mint");
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert_eq!(out.status.code(), Some(2), "stdout:\n{}", stdout);
+    assert!(
+        stdout.contains("module_names_lower_snake/bad.sv"),
+        "stdout:\n{}",
+        stdout
+    );
+}
+
+#[test]
+fn file_list_supplies_inputs() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let fixtures = std::env::current_dir()
+        .unwrap()
+        .join("fixtures/rules/module_names_lower_snake");
+    std::fs::copy(fixtures.join("bad.sv"), tmp.path().join("bad.sv")).expect("copy fixture");
+    std::fs::write(
+        tmp.path().join("files.f"),
+        "// project files\n+incdir+include\n+define+SYNTHESIS\n-y lib -v cells.v\nbad.sv\n",
+    )
+    .expect("write file list");
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("sv-mint"));
+    cmd.args(["--only", "module_names_lower_snake", "-F"]);
+    cmd.arg(tmp.path().join("files.f"));
+    let out = cmd.output().expect("failed to run sv-mint");
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert_eq!(out.status.code(), Some(2), "stdout:\n{}", stdout);
+    assert!(stdout.contains("bad.sv"), "stdout:\n{}", stdout);
+}
+
+#[test]
+fn diff_file_limits_violations_to_changed_lines() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let input = tmp.path().join("bad.sv");
+    std::fs::copy("fixtures/rules/module_names_lower_snake/bad.sv", &input).expect("copy fixture");
+    let diff = tmp.path().join("change.diff");
+    std::fs::write(
+        &diff,
+        format!(
+            "--- {path}\n+++ {path}\n@@ -3,3 +3,3 @@\n \n-module my_module;\n+module MY_MODULE;\n endmodule\n",
+            path = input.display()
+        ),
+    )
+    .expect("write diff");
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("sv-mint"));
+    cmd.args(["--only", "module_names_lower_snake", "--diff-file"])
+        .arg(&diff);
+    let out = cmd.output().expect("failed to run sv-mint");
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert_eq!(out.status.code(), Some(2), "stdout:\n{}", stdout);
+    assert!(stdout.contains("MY_MODULE"), "stdout:\n{}", stdout);
+    assert!(!stdout.contains("MyModule"), "stdout:\n{}", stdout);
+}
+
+#[test]
+fn diff_file_paths_resolve_against_the_git_toplevel() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let root = tmp.path();
+    let status = std::process::Command::new("git")
+        .args(["init", "-q"])
+        .current_dir(root)
+        .status()
+        .expect("run git");
+    assert!(status.success());
+    std::fs::write(
+        root.join("sv-mint.toml"),
+        "[[rule]]\nid = \"module_names_lower_snake\"\nnative = true\n",
+    )
+    .unwrap();
+    std::fs::create_dir_all(root.join("rtl")).unwrap();
+    std::fs::copy(
+        "fixtures/rules/module_names_lower_snake/bad.sv",
+        root.join("rtl/bad.sv"),
+    )
+    .expect("copy fixture");
+    std::fs::write(
+        root.join("change.diff"),
+        "--- a/rtl/bad.sv\n+++ b/rtl/bad.sv\n@@ -3,3 +3,3 @@\n \n-module my_module;\n+module MY_MODULE;\n endmodule\n",
+    )
+    .expect("write diff");
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("sv-mint"));
+    cmd.current_dir(root.join("rtl")).args([
+        "--no-cache",
+        "--only",
+        "module_names_lower_snake",
+        "--diff-file",
+        "../change.diff",
+    ]);
+    let out = cmd.output().expect("failed to run sv-mint");
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert_eq!(out.status.code(), Some(2), "stdout:\n{}", stdout);
+    assert!(stdout.contains("bad.sv:4:8"), "stdout:\n{}", stdout);
+    assert!(stdout.contains("MY_MODULE"), "stdout:\n{}", stdout);
+}
+
+#[test]
+fn rule_options_reach_plugins_and_are_validated() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let plugins = std::env::current_dir().unwrap().join("plugins");
+    let input = tmp.path().join("ports.sv");
+    std::fs::write(
+        &input,
+        "module ports (\n  input logic clk_in,\n  input logic rst_i\n);\nendmodule\n",
+    )
+    .expect("write input");
+    let write_config = |options: &str| {
+        let config = tmp.path().join("sv-mint.toml");
+        std::fs::write(
+            &config,
+            format!(
+                "[plugin]\ncmd = \"python3\"\nargs = [\"-u\", \"-B\"]\nroot = \"{}\"\n\n[[rule]]\nid = \"port_names_have_direction_suffix\"\n\n[rule.options]\n{}\n",
+                plugins.to_string_lossy().replace('\\', "\\\\"),
+                options
+            ),
+        )
+        .expect("write config");
+        config
+    };
+
+    let config = write_config("suffixes = { input = [\"_in\"] }");
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("sv-mint"));
+    cmd.arg("--config").arg(&config).arg(&input);
+    let out = cmd.output().expect("failed to run sv-mint");
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert_eq!(out.status.code(), Some(2), "stdout:\n{}", stdout);
+    assert!(stdout.contains("rst_i must end with _in"), "stdout:\n{}", stdout);
+    assert!(!stdout.contains("clk_in"), "stdout:\n{}", stdout);
+
+    let config = write_config("suffixes = { input = \"_in\" }");
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("sv-mint"));
+    cmd.arg("--config").arg(&config).arg(&input);
+    let out = cmd.output().expect("failed to run sv-mint");
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert_eq!(out.status.code(), Some(3), "stderr:\n{}", stderr);
+    assert!(
+        stderr.contains("options.suffixes.input: expected array"),
+        "stderr:\n{}",
+        stderr
+    );
+
+    let config = write_config(
+        "suffixes = { input = [\"_in\"] }\n\n[[override]]\npaths = [\"tb/**\"]\n\n[[override.rule]]\nid = \"port_names_have_direction_suffix\"\noptions = { suffixes = { input = \"_in\" } }",
+    );
+    std::fs::create_dir_all(tmp.path().join("tb")).unwrap();
+    let tb_input = tmp.path().join("tb/ports.sv");
+    std::fs::copy(&input, &tb_input).unwrap();
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("sv-mint"));
+    cmd.arg("--config")
+        .arg(&config)
+        .arg("--no-cache")
+        .arg(&input)
+        .arg(&tb_input);
+    let out = cmd.output().expect("failed to run sv-mint");
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert_eq!(out.status.code(), Some(3), "stdout:\n{}", stdout);
+    assert!(stdout.contains("rst_i must end with _in"), "stdout:\n{}", stdout);
+    assert!(
+        stdout.contains("options.suffixes.input: expected array"),
+        "stdout:\n{}",
+        stdout
+    );
+}
+
+#[test]
+fn extends_and_path_overrides_apply_per_file() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let root = tmp.path();
+    let plugins = std::env::current_dir().unwrap().join("plugins");
+    std::fs::create_dir_all(root.join("shared")).unwrap();
+    std::fs::write(
+        root.join("shared/base.toml"),
+        format!(
+            "[plugin]\ncmd = \"python3\"\nargs = [\"-u\", \"-B\"]\nroot = \"{}\"\n\n[[rule]]\nid = \"module_names_lower_snake\"\nseverity = \"error\"\n",
+            plugins.to_string_lossy().replace('\\', "\\\\")
+        ),
+    )
+    .unwrap();
+    std::fs::write(
+        root.join("sv-mint.toml"),
+        "extends = [\"shared/base.toml\"]\n\n[[override]]\npaths = [\"tb/**\"]\n\n[[override.rule]]\nid = \"module_names_lower_snake\"\nenabled = false\n",
+    )
+    .unwrap();
+    for dir in ["rtl", "tb"] {
+        std::fs::create_dir_all(root.join(dir)).unwrap();
+        std::fs::write(root.join(dir).join("top.sv"), "module BadName;\nendmodule\n").unwrap();
+    }
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("sv-mint"));
+    cmd.arg("--config").arg(root.join("sv-mint.toml"));
+    cmd.arg(root.join("rtl/top.sv")).arg(root.join("tb/top.sv"));
+    let out = cmd.output().expect("failed to run sv-mint");
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert_eq!(out.status.code(), Some(2), "stdout:\n{}", stdout);
+    assert!(
+        stdout.contains("rtl/top.sv:1:8: [error] module_names_lower_snake"),
+        "stdout:\n{}",
+        stdout
+    );
+    assert!(!stdout.contains("tb/top.sv"), "stdout:\n{}", stdout);
+}
+
+#[test]
+fn configs_are_discovered_per_input() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let root = tmp.path();
+    let plugins = std::env::current_dir().unwrap().join("plugins");
+    std::fs::create_dir_all(root.join(".git")).unwrap();
+    for (ip, severity) in [("a", "error"), ("b", "warning")] {
+        let dir = root.join("ip").join(ip);
+        std::fs::create_dir_all(dir.join("rtl")).unwrap();
+        std::fs::write(
+            dir.join("sv-mint.toml"),
+            format!(
+                "[plugin]\ncmd = \"python3\"\nargs = [\"-u\", \"-B\"]\nroot = \"{}\"\n\n[[rule]]\nid = \"module_names_lower_snake\"\nseverity = \"{}\"\n",
+                plugins.to_string_lossy().replace('\\', "\\\\"),
+                severity
+            ),
+        )
+        .unwrap();
+        std::fs::write(dir.join("rtl/top.sv"), "module BadName;\nendmodule\n").unwrap();
+    }
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("sv-mint"));
+    cmd.current_dir(root.join("ip")).arg(".");
+    let out = cmd.output().expect("failed to run sv-mint");
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert_eq!(out.status.code(), Some(2), "stdout:\n{}\nstderr:\n{}", stdout, stderr);
+    assert!(
+        stdout.contains("a/rtl/top.sv:1:8: [error] module_names_lower_snake"),
+        "stdout:\n{}",
+        stdout
+    );
+    assert!(
+        stdout.contains("b/rtl/top.sv:1:8: [warning] module_names_lower_snake"),
+        "stdout:\n{}",
+        stdout
+    );
+
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("sv-mint"));
+    cmd.current_dir(root.join("ip/b/rtl")).arg("top.sv");
+    let out = cmd.output().expect("failed to run sv-mint");
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(
+        stdout.contains("top.sv:1:8: [warning] module_names_lower_snake"),
+        "stdout:\n{}",
+        stdout
+    );
+}
+
+#[test]
+fn discovered_configs_must_agree_on_the_output_format() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let root = tmp.path();
+    std::fs::create_dir_all(root.join(".git")).unwrap();
+    for (ip, format) in [("a", "sarif"), ("b", "json")] {
+        let dir = root.join(ip);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("sv-mint.toml"),
+            format!("[output]\nformat = \"{format}\"\n\n[[rule]]\nid = \"module_names_lower_snake\"\nnative = true\n"),
+        )
+        .unwrap();
+        std::fs::write(dir.join("top.sv"), "module BadName;\nendmodule\n").unwrap();
+    }
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("sv-mint"));
+    cmd.current_dir(root).args(["--no-cache", "."]);
+    let out = cmd.output().expect("failed to run sv-mint");
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert_eq!(out.status.code(), Some(3), "stderr:\n{}", stderr);
+    assert!(out.stdout.is_empty());
+    assert!(
+        stderr.contains("configs set different [output] formats (sarif and json)"),
+        "stderr:\n{}",
+        stderr
+    );
+
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("sv-mint"));
+    cmd.current_dir(root).args(["--no-cache", "--format", "json", "."]);
+    let out = cmd.output().expect("failed to run sv-mint");
+    assert_eq!(out.status.code(), Some(2));
+    let doc: serde_json::Value = serde_json::from_slice(&out.stdout).expect("json");
+    assert_eq!(doc["summary"]["files"], 2);
+}
+
+#[test]
+fn rules_subcommand_lists_metadata() {
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("sv-mint"));
+    cmd.args(["rules", "--json", "--disable", "case_has_default_branch"]);
+    let out = cmd.output().expect("failed to run sv-mint");
+    assert_eq!(
+        out.status.code(),
+        Some(0),
+        "stderr:\n{}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    let rules: serde_json::Value = serde_json::from_slice(&out.stdout).expect("json");
+    let rules = rules.as_array().expect("array");
+    assert!(!rules.is_empty());
+    for rule in rules {
+        assert!(rule["description"].is_string(), "missing description: {}", rule);
+    }
+    let case = rules
+        .iter()
+        .find(|r| r["id"] == "case_has_default_branch")
+        .expect("rule listed");
+    assert_eq!(case["stage"], "cst");
+    assert_eq!(case["enabled"], false);
+    assert!(case["script"]
+        .as_str()
+        .unwrap()
+        .ends_with("case_has_default_branch.cst.py"));
+
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("sv-mint"));
+    cmd.arg("rules");
+    let out = cmd.output().expect("failed to run sv-mint");
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert_eq!(out.status.code(), Some(0));
+    let line = stdout
+        .lines()
+        .find(|l| l.starts_with("module_names_lower_snake "))
+        .expect("text row");
+    assert!(line.contains("module names use lower_snake_case"), "{}", line);
+}
+
+#[test]
+fn explain_prints_examples_and_runs_rule() {
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("sv-mint"));
+    cmd.args(["explain", "module_names_lower_snake", "--run"]);
+    let out = cmd.output().expect("failed to run sv-mint");
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert_eq!(out.status.code(), Some(0), "stdout:\n{}", stdout);
+    assert!(stdout.contains("Why: "), "stdout:\n{}", stdout);
+    assert!(
+        stdout.contains("Good (fixtures/rules/module_names_lower_snake/good.sv):"),
+        "stdout:\n{}",
+        stdout
+    );
+    assert!(stdout.contains("    module MyModule;"), "stdout:\n{}", stdout);
+    assert!(
+        stdout.contains("bad.sv:1:8: [warning] module_names_lower_snake: MyModule must use lower_snake_case"),
+        "stdout:\n{}",
+        stdout
+    );
+
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("sv-mint"));
+    cmd.args(["explain", "no_such_rule"]);
+    let out = cmd.output().expect("failed to run sv-mint");
+    assert_eq!(out.status.code(), Some(3));
+    assert!(String::from_utf8_lossy(&out.stderr).contains("rule no_such_rule not found"));
+}
+
+#[test]
+fn native_rules_run_without_plugin_host() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let cfg = tmp.path().join("sv-mint.toml");
+    std::fs::write(
+        &cfg,
+        "[plugin]\ncmd = \"sv-mint-no-such-interpreter\"\n\n[[rule]]\nid = \"module_names_lower_snake\"\nnative = true\n",
+    )
+    .unwrap();
+    let src = tmp.path().join("top.sv");
+    std::fs::write(&src, "module BadName;\nendmodule\n").unwrap();
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("sv-mint"));
+    cmd.arg("--config").arg(&cfg).arg(&src);
+    let out = cmd.output().expect("failed to run sv-mint");
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert_eq!(
+        out.status.code(),
+        Some(2),
+        "stdout:\n{}\nstderr:\n{}",
+        stdout,
+        String::from_utf8_lossy(&out.stderr)
+    );
+    assert!(
+        stdout.contains("top.sv:1:8: [warning] module_names_lower_snake: BadName must use lower_snake_case"),
+        "stdout:\n{}",
+        stdout
+    );
+}
+
+#[test]
+fn rules_are_routed_to_their_named_host() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let root = tmp.path();
+    let plugins = std::env::current_dir().unwrap().join("plugins");
+    std::fs::write(
+        root.join("line_host.py"),
+        r#"import json, sys
+
+init = json.loads(sys.stdin.readline())
+scripts = [s["path"] for s in init["scripts"]]
+print(json.dumps({"type": "ready", "protocol_version": 1}), flush=True)
+for line in sys.stdin:
+    req = json.loads(line)
+    if req["kind"] == "shutdown":
+        break
+    out = []
+    if "first_line_marker" in req["rules"]["enabled"] and not req["payload"]["text"].startswith("//"):
+        loc = {"line": 1, "col": 1, "end_line": 1, "end_col": 1}
+        msg = "first line must be a comment (" + scripts[0].rsplit("/", 1)[-1] + ")"
+        out.append({"rule_id": "first_line_marker", "severity": "warning", "message": msg, "location": loc})
+    print(json.dumps({"type": "violations", "violations": out}), flush=True)
+"#,
+    )
+    .unwrap();
+    std::fs::write(root.join("first_line_marker.raw.lines"), "").unwrap();
+    std::fs::write(
+        root.join("sv-mint.toml"),
+        format!(
+            "[plugin]\ncmd = \"python3\"\nargs = [\"-u\", \"-B\"]\nroot = \"{}\"\n\n[plugin.hosts.lines]\ncmd = \"python3\"\nargs = [\"-u\", \"{}\"]\n\n[[rule]]\nid = \"module_names_lower_snake\"\n\n[[rule]]\nid = \"first_line_marker\"\nhost = \"lines\"\nscript = \"{}\"\n",
+            plugins.to_string_lossy().replace('\\', "\\\\"),
+            root.join("line_host.py").to_string_lossy().replace('\\', "\\\\"),
+            root.join("first_line_marker.raw.lines").to_string_lossy().replace('\\', "\\\\"),
+        ),
+    )
+    .unwrap();
+    let src = root.join("top.sv");
+    std::fs::write(&src, "module BadName;\nendmodule\n").unwrap();
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("sv-mint"));
+    cmd.arg("--config").arg(root.join("sv-mint.toml")).arg(&src);
+    let out = cmd.output().expect("failed to run sv-mint");
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert_eq!(
+        out.status.code(),
+        Some(2),
+        "stdout:\n{}\nstderr:\n{}",
+        stdout,
+        String::from_utf8_lossy(&out.stderr)
+    );
+    assert!(
+        stdout.contains("first_line_marker: first line must be a comment (first_line_marker.raw.lines)"),
+        "stdout:\n{}",
+        stdout
+    );
+    assert!(
+        stdout.contains("module_names_lower_snake: BadName must use lower_snake_case"),
+        "stdout:\n{}",
+        stdout
+    );
+}
+
+#[test]
+fn incompatible_plugin_hosts_are_refused() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let root = tmp.path();
+    let src = root.join("top.sv");
+    std::fs::write(&src, "module top;\nendmodule\n").unwrap();
+    std::fs::write(root.join("probe.raw.txt"), "").unwrap();
+    let cases = [
+        (
+            r#"{"type": "ready", "protocol_version": 99}"#,
+            "plugin protocol mismatch: host speaks v99, sv-mint speaks v1",
+        ),
+        (
+            r#"{"type": "ready"}"#,
+            "plugin protocol mismatch: host speaks an unversioned protocol, sv-mint speaks v1",
+        ),
+        (
+            r#"{"type": "ready", "protocol_version": 1, "schemas": {"ast": [7]}}"#,
+            "plugin host does not support ast schema 1",
+        ),
+    ];
+    for (idx, (ready, expected)) in cases.iter().enumerate() {
+        let host = root.join(format!("host{idx}.py"));
+        std::fs::write(
+            &host,
+            format!("import sys\nsys.stdin.readline()\nprint('{ready}', flush=True)\nsys.stdin.read()\n"),
+        )
+        .unwrap();
+        let cfg = root.join(format!("sv-mint{idx}.toml"));
+        std::fs::write(
+            &cfg,
+            format!(
+                "[plugin.hosts.probe]\ncmd = \"python3\"\nargs = [\"-u\", \"{}\"]\n\n[[rule]]\nid = \"probe\"\nhost = \"probe\"\nscript = \"{}\"\n",
+                host.to_string_lossy().replace('\\', "\\\\"),
+                root.join("probe.raw.txt").to_string_lossy().replace('\\', "\\\\"),
+            ),
+        )
+        .unwrap();
+        let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("sv-mint"));
+        cmd.arg("--config").arg(&cfg).arg(&src);
+        let out = cmd.output().expect("failed to run sv-mint");
+        let stderr = String::from_utf8_lossy(&out.stderr);
+        assert_eq!(out.status.code(), Some(3), "stderr:\n{}", stderr);
+        assert!(stderr.contains(expected), "stderr:\n{}", stderr);
+    }
+}
+
+#[test]
+fn hosts_start_only_for_enabled_rules() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let root = tmp.path();
+    let plugins = std::env::current_dir().unwrap().join("plugins");
+    std::fs::write(root.join("broken.py"), "import sys\nsys.exit(1)\n").unwrap();
+    std::fs::write(root.join("probe.raw.txt"), "").unwrap();
+    let write_config = |overrides: &str| {
+        std::fs::write(
+            root.join("sv-mint.toml"),
+            format!(
+                "[plugin]\ncmd = \"python3\"\nargs = [\"-u\", \"-B\"]\nroot = \"{}\"\n\n[plugin.hosts.broken]\ncmd = \"python3\"\nargs = [\"-u\", \"{}\"]\n\n[[rule]]\nid = \"module_names_lower_snake\"\n\n[[rule]]\nid = \"probe\"\nhost = \"broken\"\nscript = \"{}\"\nenabled = false\n{}",
+                plugins.to_string_lossy().replace('\\', "\\\\"),
+                root.join("broken.py").to_string_lossy().replace('\\', "\\\\"),
+                root.join("probe.raw.txt").to_string_lossy().replace('\\', "\\\\"),
+                overrides,
+            ),
+        )
+        .unwrap();
+    };
+    let src = root.join("top.sv");
+    std::fs::write(&src, "module BadName;\nendmodule\n").unwrap();
+
+    write_config("");
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("sv-mint"));
+    cmd.arg("--no-cache")
+        .arg("--config")
+        .arg(root.join("sv-mint.toml"))
+        .arg(&src);
+    let out = cmd.output().expect("failed to run sv-mint");
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert_eq!(out.status.code(), Some(2), "stdout:\n{}\nstderr:\n{}", stdout, stderr);
+    assert!(stdout.contains("module_names_lower_snake"), "stdout:\n{}", stdout);
+
+    write_config("\n[[override]]\npaths = [\"*.sv\"]\n\n[[override.rule]]\nid = \"probe\"\nenabled = true\n");
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("sv-mint"));
+    cmd.arg("--no-cache")
+        .arg("--config")
+        .arg(root.join("sv-mint.toml"))
+        .arg(&src);
+    let out = cmd.output().expect("failed to run sv-mint");
+    assert_eq!(
+        out.status.code(),
+        Some(3),
+        "stderr:\n{}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+}
+
+#[test]
+fn rule_host_reports_its_version_to_mismatched_cores() {
+    let out = Command::new("python3")
+        .args(["-u", "-B", "plugins/lib/rule_host.py"])
+        .write_stdin("{\"protocol_version\": 99, \"scripts\": []}\n")
+        .output()
+        .expect("failed to run rule_host.py");
+    let ready: serde_json::Value = serde_json::from_slice(&out.stdout).expect("ready json");
+    assert_eq!(ready["type"], "ready");
+    assert_eq!(ready["protocol_version"], 1);
+}
+
+#[test]
+fn crashing_rule_scripts_do_not_abort_the_stage() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let root = tmp.path();
+    let plugins = std::env::current_dir().unwrap().join("plugins");
+    let boom = root.join("boom.raw.py");
+    std::fs::write(&boom, "def check(req):\n    raise ValueError(\"kaboom\")\n").unwrap();
+    let src = root.join("top.sv");
+    std::fs::write(&src, "`define bad_macro 1\nmodule top;\nendmodule\n").unwrap();
+    for policy in ["error", "warning"] {
+        let cfg = root.join(format!("sv-mint-{policy}.toml"));
+        std::fs::write(
+            &cfg,
+            format!(
+                "[plugin]\ncmd = \"python3\"\nargs = [\"-u\", \"-B\"]\nroot = \"{}\"\non_rule_crash = \"{}\"\n\n[[rule]]\nid = \"boom\"\nscript = \"{}\"\n\n[[rule]]\nid = \"macro_names_uppercase\"\n",
+                plugins.to_string_lossy().replace('\\', "\\\\"),
+                policy,
+                boom.to_string_lossy().replace('\\', "\\\\"),
+            ),
+        )
+        .unwrap();
+        let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("sv-mint"));
+        cmd.arg("--config").arg(&cfg).arg(&src);
+        let out = cmd.output().expect("failed to run sv-mint");
+        let stdout = String::from_utf8_lossy(&out.stdout);
+        assert_eq!(
+            out.status.code(),
+            Some(2),
+            "stdout:\n{}\nstderr:\n{}",
+            stdout,
+            String::from_utf8_lossy(&out.stderr)
+        );
+        let crash = stdout
+            .lines()
+            .find(|line| line.contains("sys.plugin.rule_crashed"))
+            .unwrap_or_else(|| panic!("stdout:\n{}", stdout));
+        assert!(
+            crash.contains(&format!("[{policy}] sys.plugin.rule_crashed")),
+            "{}",
+            crash
+        );
+        assert!(crash.contains("boom.raw.py"), "{}", crash);
+        assert!(crash.contains("ValueError: kaboom"), "{}", crash);
+        assert!(crash.contains("boom.raw.py:2 in check"), "{}", crash);
+        assert!(
+            stdout.contains("macro_names_uppercase: `define bad_macro should use ALL_CAPS"),
+            "stdout:\n{}",
+            stdout
+        );
+    }
+}
+
+#[test]
+fn plugin_hosts_are_restarted_within_budget() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let root = tmp.path();
+    let host = root.join("flaky_host.py");
+    std::fs::write(
+        &host,
+        r#"import json, os, sys
+
+marker = sys.argv[1]
+sys.stdin.readline()
+print(json.dumps({"type": "ready", "protocol_version": 1}), flush=True)
+served = 0
+for line in sys.stdin:
+    req = json.loads(line)
+    if req["kind"] == "shutdown":
+        break
+    with open(marker + ".log", "a") as log:
+        log.write(req["stage"] + "\n")
+    if "second" in marker:
+        if served == 1:
+            sys.exit(1)
+    elif not os.path.exists(marker) or "always" in marker:
+        open(marker, "w").close()
+        sys.exit(1)
+    served += 1
+    loc = {"line": 1, "col": 1, "end_line": 1, "end_col": 1}
+    out = [{"rule_id": "flaky", "severity": "warning", "message": "checked", "location": loc}]
+    print(json.dumps({"type": "violations", "violations": out}), flush=True)
+"#,
+    )
+    .unwrap();
+    std::fs::write(root.join("flaky.raw.txt"), "").unwrap();
+    let script = root.join("flaky.raw.txt").to_string_lossy().replace('\\', "\\\\");
+    let write_cfg = |name: &str, marker: &str, max_restarts: u32, extra: &str| {
+        let cfg = root.join(name);
+        std::fs::write(
+            &cfg,
+            format!(
+                "[plugin]\nmax_restarts = {}\n\n[plugin.hosts.flaky]\ncmd = \"python3\"\nargs = [\"-u\", \"{}\", \"{}\"]\n\n[[rule]]\nid = \"flaky\"\nhost = \"flaky\"\nscript = \"{}\"\n{}",
+                max_restarts,
+                host.to_string_lossy().replace('\\', "\\\\"),
+                root.join(marker).to_string_lossy().replace('\\', "\\\\"),
+                script,
+                extra,
+            ),
+        )
+        .unwrap();
+        cfg
+    };
+    let first = root.join("a.sv");
+    let second = root.join("b.sv");
+    std::fs::write(&first, "module a;\nendmodule\n").unwrap();
+    std::fs::write(&second, "module b;\nendmodule\n").unwrap();
+
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("sv-mint"));
+    cmd.arg("--config")
+        .arg(write_cfg("once.toml", "crashed_once", 3, ""))
+        .arg(&first);
+    let out = cmd.output().expect("failed to run sv-mint");
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert_eq!(out.status.code(), Some(2), "stdout:\n{}\nstderr:\n{}", stdout, stderr);
+    assert!(
+        stdout.contains("a.sv:1:1: [warning] flaky: checked"),
+        "stdout:\n{}",
+        stdout
+    );
+    assert!(stdout.contains("restarting plugin host flaky"), "stdout:\n{}", stdout);
+
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("sv-mint"));
+    cmd.arg("--config")
+        .arg(write_cfg("broken.toml", "always_crash", 1, ""))
+        .arg(&first)
+        .arg(&second);
+    let out = cmd.output().expect("failed to run sv-mint");
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert_eq!(out.status.code(), Some(3), "stdout:\n{}", stdout);
+    assert_eq!(
+        stdout.matches("restarting plugin host").count(),
+        1,
+        "stdout:\n{}",
+        stdout
+    );
+    assert!(stdout.contains("plugin exit nonzero"), "stdout:\n{}", stdout);
+
+    let pp_rule = format!(
+        "\n[[rule]]\nid = \"flaky_pp\"\nhost = \"flaky\"\nstage = \"pp_text\"\nscript = \"{}\"\n",
+        script
+    );
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("sv-mint"));
+    cmd.arg("--config")
+        .arg(write_cfg("second.toml", "crash_second", 5, &pp_rule))
+        .arg(&first);
+    let out = cmd.output().expect("failed to run sv-mint");
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert_eq!(out.status.code(), Some(3), "stdout:\n{}", stdout);
+    assert_eq!(
+        stdout.matches("restarting plugin host").count(),
+        1,
+        "stdout:\n{}",
+        stdout
+    );
+    let log = std::fs::read_to_string(root.join("crash_second.log")).unwrap();
+    assert_eq!(log, "raw_text\npp_text\nraw_text\npp_text\n");
+}
+
+#[test]
+fn profile_reports_rule_and_phase_timings() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let src = tmp.path().join("top.sv");
+    std::fs::write(&src, "`define bad_macro 1\nmodule BadName;\nendmodule\n").unwrap();
+
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("sv-mint"));
+    cmd.arg("--config").arg("sv-mint.toml").arg("--profile=json").arg(&src);
+    let out = cmd.output().expect("failed to run sv-mint");
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert_eq!(out.status.code(), Some(2), "stderr:\n{}", stderr);
+    let report: serde_json::Value = serde_json::from_str(&stderr).expect("profile json");
+    let names = |key: &str| -> Vec<String> {
+        report[key]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|row| row["name"].as_str().unwrap().to_string())
+            .collect()
+    };
+    let rules = names("rules");
+    assert!(rules.contains(&"module_names_lower_snake".to_string()), "{:?}", rules);
+    assert!(rules.contains(&"macro_names_uppercase".to_string()), "{:?}", rules);
+    let phases = names("phases");
+    for phase in ["parse.preprocess", "parse.parse", "serialize", "stage.ast"] {
+        assert!(phases.contains(&phase.to_string()), "{:?}", phases);
+    }
+    let totals: Vec<f64> = report["rules"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|row| row["total_ms"].as_f64().unwrap())
+        .collect();
+    assert!(totals.windows(2).all(|w| w[0] >= w[1]), "{:?}", totals);
+
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("sv-mint"));
+    cmd.arg("--config").arg("sv-mint.toml").arg("--profile").arg(&src);
+    let out = cmd.output().expect("failed to run sv-mint");
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(stderr.starts_with("RULE "), "stderr:\n{}", stderr);
+    assert!(stderr.contains("\nPHASE "), "stderr:\n{}", stderr);
+}
+
+#[test]
+fn unchanged_files_replay_cached_results() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let root = tmp.path();
+    let calls = root.join("calls.log");
+    let host = root.join("counting_host.py");
+    std::fs::write(
+        &host,
+        r#"import json, os, sys
+
+calls = os.environ["SV_MINT_TEST_CALLS"]
+sys.stdin.readline()
+print(json.dumps({"type": "ready", "protocol_version": 1}), flush=True)
+for line in sys.stdin:
+    req = json.loads(line)
+    if req["kind"] == "shutdown":
+        break
+    with open(calls, "a") as f:
+        f.write(req["path"] + "\n")
+    loc = {"line": 1, "col": 1, "end_line": 1, "end_col": 1}
+    out = [{"rule_id": "counted", "severity": "warning", "message": "seen", "location": loc}]
+    print(json.dumps({"type": "violations", "violations": out}), flush=True)
+"#,
+    )
+    .unwrap();
+    let script = root.join("counted.raw.txt");
+    std::fs::write(&script, "v1").unwrap();
+    let cfg = root.join("sv-mint.toml");
+    std::fs::write(
+        &cfg,
+        format!(
+            "[plugin.hosts.counting]\ncmd = \"python3\"\nargs = [\"-u\", \"{}\"]\n\n[[rule]]\nid = \"counted\"\nhost = \"counting\"\nscript = \"{}\"\n",
+            host.to_string_lossy().replace('\\', "\\\\"),
+            script.to_string_lossy().replace('\\', "\\\\"),
+        ),
+    )
+    .unwrap();
+    let src = root.join("top.sv");
+    std::fs::write(&src, "module top;\nendmodule\n").unwrap();
+    let cache_dir = root.join("cache");
+    let run = |extra: &[&str]| {
+        let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("sv-mint"));
+        cmd.env("SV_MINT_TEST_CALLS", &calls);
+        cmd.arg("--config").arg(&cfg).arg("--cache-dir").arg(&cache_dir);
+        let out = cmd.args(extra).arg(&src).output().expect("failed to run sv-mint");
+        let stdout = String::from_utf8_lossy(&out.stdout).to_string();
+        assert_eq!(out.status.code(), Some(2), "stdout:\n{}", stdout);
+        assert!(
+            stdout.contains("top.sv:1:1: [warning] counted: seen"),
+            "stdout:\n{}",
+            stdout
+        );
+        std::fs::read_to_string(&calls).unwrap_or_default().lines().count()
+    };
+
+    assert_eq!(run(&[]), 1);
+    assert!(cache_dir.is_dir());
+    assert_eq!(run(&[]), 1, "second run should replay the cache");
+
+    std::fs::write(&script, "v2").unwrap();
+    assert_eq!(run(&[]), 2, "script changes invalidate the cache");
+
+    std::fs::write(&src, "module top;\n\nendmodule\n").unwrap();
+    assert_eq!(run(&[]), 3, "content changes invalidate the cache");
+    assert_eq!(run(&[]), 3);
+    assert_eq!(run(&["--profile"]), 3, "profiling keeps the cache");
+
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("sv-mint"));
+    let out = cmd
+        .env("SV_MINT_TEST_CALLS", &calls)
+        .arg("--config")
+        .arg(&cfg)
+        .arg("--no-cache")
+        .arg(&src)
+        .output()
+        .expect("failed to run sv-mint");
+    assert_eq!(out.status.code(), Some(2));
+    assert_eq!(std::fs::read_to_string(&calls).unwrap().lines().count(), 4);
+
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("sv-mint"));
+    let out = cmd
+        .env("SV_MINT_TEST_CALLS", &calls)
+        .env("XDG_CACHE_HOME", root.join("xdg"))
+        .arg("--config")
+        .arg(&cfg)
+        .arg(&src)
+        .output()
+        .expect("failed to run sv-mint");
+    assert_eq!(out.status.code(), Some(2));
+    assert!(root.join("xdg/sv-mint").is_dir());
+    assert!(!root.join(".sv-mint-cache").exists());
+}
+
+#[test]
+fn watch_relints_changed_files_and_their_includers() {
+    watch_relints(false);
+}
+
+#[test]
+fn watch_with_cache_relints_includers_of_changed_headers() {
+    watch_relints(true);
+}
+
+fn watch_relints(cache: bool) {
+    use std::io::{BufRead, BufReader};
+    use std::sync::mpsc;
+    use std::time::Duration;
+
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let root = tmp.path();
+    let plugins = std::env::current_dir().unwrap().join("plugins");
+    std::fs::write(
+        root.join("sv-mint.toml"),
+        format!(
+            "[plugin]\ncmd = \"python3\"\nargs = [\"-u\", \"-B\"]\nroot = \"{}\"\n\n[svparser]\nignore_include = false\ninclude_paths = [\"{}\"]\n\n[[rule]]\nid = \"module_names_lower_snake\"\nnative = true\n\n[[rule]]\nid = \"macro_names_uppercase\"\n",
+            plugins.to_string_lossy().replace('\\', "\\\\"),
+            root.to_string_lossy().replace('\\', "\\\\"),
+        ),
+    )
+    .unwrap();
+    let header = root.join("defs.svh");
+    std::fs::write(&header, "`define WIDTH 8\n").unwrap();
+    let top = root.join("top.sv");
+    std::fs::write(&top, "`include \"defs.svh\"\nmodule BadName;\nendmodule\n").unwrap();
+    let other = root.join("other.sv");
+    std::fs::write(&other, "module other;\nendmodule\n").unwrap();
+
+    let mut cmd = std::process::Command::new(assert_cmd::cargo::cargo_bin!("sv-mint"));
+    cmd.arg("--config").arg(root.join("sv-mint.toml"));
+    if cache {
+        cmd.arg("--cache-dir").arg(root.join("cache"));
+    } else {
+        cmd.arg("--no-cache");
+    }
+    let mut child = cmd
+        .arg("--watch")
+        .arg(&top)
+        .arg(&other)
+        .stdout(std::process::Stdio::piped())
+        .spawn()
+        .expect("failed to spawn sv-mint");
+    let stdout = child.stdout.take().unwrap();
+    let (tx, rx) = mpsc::channel();
+    std::thread::spawn(move || {
+        for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+            if tx.send(line).is_err() {
+                break;
+            }
+        }
+    });
+    let next_cycle = || {
+        let mut seen = Vec::new();
+        loop {
+            let line = rx
+                .recv_timeout(Duration::from_secs(20))
+                .unwrap_or_else(|_| panic!("no watch summary; output so far:\n{}", seen.join("\n")));
+            if line.starts_with("[watch]") {
+                seen.push(line);
+                return seen;
+            }
+            seen.push(line);
+        }
+    };
+
+    let first = next_cycle();
+    let summary = first.last().unwrap();
+    assert!(
+        summary.contains("re-linted 2 file(s): 1 violation(s) in 1 of 2 file(s)"),
+        "{:?}",
+        first
+    );
+    assert!(
+        first.iter().any(|l| l.contains("BadName must use lower_snake_case")),
+        "{:?}",
+        first
+    );
+
+    std::fs::write(&header, "`define WIDTH 8\nmodule AlsoBad;\nendmodule\n").unwrap();
+    let second = next_cycle();
+    assert!(
+        second
+            .last()
+            .unwrap()
+            .contains("re-linted 1 file(s): 2 violation(s) in 1 of 2 file(s)"),
+        "{:?}",
+        second
+    );
+    assert!(
+        second.iter().any(|l| l.contains("AlsoBad must use lower_snake_case")),
+        "{:?}",
+        second
+    );
+
+    std::fs::write(&top, "module top;\nendmodule\n").unwrap();
+    let third = next_cycle();
+    assert!(
+        third
+            .last()
+            .unwrap()
+            .contains("re-linted 1 file(s): 0 violation(s) in 0 of 2 file(s)"),
+        "{:?}",
+        third
+    );
+
+    child.kill().unwrap();
+    child.wait().unwrap();
+}
+
+#[test]
+fn stdin_input_uses_the_virtual_filename() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let root = tmp.path();
+    let plugins = std::env::current_dir().unwrap().join("plugins");
+    std::fs::write(
+        root.join("sv-mint.toml"),
+        format!(
+            "[plugin]\ncmd = \"python3\"\nargs = [\"-u\", \"-B\"]\nroot = \"{}\"\n\n[svparser]\nignore_include = false\ninclude_paths = [\"{}\"]\n\n[[rule]]\nid = \"module_name_matches_filename\"\n",
+            plugins.to_string_lossy().replace('\\', "\\\\"),
+            root.to_string_lossy().replace('\\', "\\\\"),
+        ),
+    )
+    .unwrap();
+    std::fs::write(root.join("defs.svh"), "`define WIDTH 8\n").unwrap();
+    let virtual_path = root.join("top.sv");
+    let source = "`include \"defs.svh\"\nmodule wrong;\n  logic [`WIDTH-1:0] data;\nendmodule\n";
+
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("sv-mint"));
+    let out = cmd
+        .arg("--no-cache")
+        .arg("--stdin-filename")
+        .arg(&virtual_path)
+        .arg("-")
+        .write_stdin(source)
+        .output()
+        .expect("failed to run sv-mint");
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert_eq!(out.status.code(), Some(2), "{}", stdout);
+    assert!(
+        stdout.contains(&format!(
+            "{}:2:8: [warning] module_name_matches_filename: module name wrong should match file name top",
+            virtual_path.display()
+        )),
+        "{}",
+        stdout
+    );
+    assert!(!virtual_path.exists());
+
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("sv-mint"));
+    let out = cmd
+        .arg("--stdin-filename")
+        .arg(&virtual_path)
+        .arg("-")
+        .arg(root.join("defs.svh"))
+        .write_stdin(source)
+        .output()
+        .expect("failed to run sv-mint");
+    assert_eq!(out.status.code(), Some(3));
+    assert!(String::from_utf8_lossy(&out.stderr).contains("cannot be combined with other inputs"));
+}
@@ -0,0 +1,70 @@
+use sv_mint::config::ConfigBuilder;
+use sv_mint::core::size_guard::StageStatus;
+use sv_mint::linter::{Linter, ParseStatus};
+use sv_mint::types::Stage;
+
+fn linter() -> Linter {
+    let cfg = ConfigBuilder::new()
+        .plugin_root("plugins")
+        .plugin_command("python3", ["-u", "-B"])
+        .stages([Stage::RawText, Stage::Cst, Stage::Ast])
+        .native_rule("module_names_lower_snake")
+        .rule("sensitivity_list_uses_commas")
+        .severity("sensitivity_list_uses_commas", "error")
+        .build()
+        .expect("build config");
+    Linter::new(cfg)
+}
+
+#[test]
+fn lint_source_returns_structured_reports() {
+    let mut linter = linter();
+    let text = std::fs::read_to_string("fixtures/rules/sensitivity_list_uses_commas/bad.sv")
+        .unwrap()
+        .replace("sensitivity_or_violation", "BadName");
+    let report = linter.lint_source("virtual/bad_name.sv", &text).unwrap();
+    assert_eq!(report.parse_status, ParseStatus::Parsed);
+    let ids: Vec<&str> = report.violations.iter().map(|v| v.rule_id.as_str()).collect();
+    assert_eq!(
+        ids,
+        [
+            "sensitivity_list_uses_commas",
+            "sensitivity_list_uses_commas",
+            "module_names_lower_snake"
+        ],
+        "{:?}",
+        report.violations
+    );
+    assert_eq!(report.violations[0].severity, sv_mint::types::Severity::Error);
+    assert!(!report.violations[1].fixes.is_empty());
+    let stages: Vec<(&str, StageStatus)> = report
+        .stage_outcomes
+        .iter()
+        .map(|o| (o.stage.as_str(), o.status))
+        .collect();
+    assert_eq!(
+        stages,
+        [
+            ("raw_text", StageStatus::Skipped),
+            ("cst", StageStatus::Ran),
+            ("ast", StageStatus::Ran)
+        ]
+    );
+
+    let broken = linter
+        .lint_source("virtual/broken.sv", "module m;\n  logic [`WIDTH-1:0] d;\nendmodule\n")
+        .unwrap();
+    assert!(matches!(broken.parse_status, ParseStatus::Failed { .. }));
+    assert_eq!(broken.violations[0].rule_id, "sys.parse.failed");
+
+    let good = linter
+        .lint_file("fixtures/rules/sensitivity_list_uses_commas/good.sv")
+        .unwrap();
+    assert!(good.violations.is_empty(), "{:?}", good.violations);
+}
+
+#[test]
+fn config_builder_rejects_unknown_native_rules() {
+    let err = ConfigBuilder::new().native_rule("no_such_rule").build().err().unwrap();
+    assert!(err.to_string().contains("not a native rule"), "{}", err);
+}
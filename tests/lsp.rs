@@ -0,0 +1,166 @@
+use serde_json::{json, Value};
+use std::io::{BufReader, Write};
+use std::path::Path;
+use std::process::{Child, ChildStdin, ChildStdout, Command, Stdio};
+use sv_mint::lsp::{read_message, write_message};
+
+struct Client {
+    child: Child,
+    stdin: ChildStdin,
+    stdout: BufReader<ChildStdout>,
+    next_id: u64,
+}
+
+impl Client {
+    fn start() -> Self {
+        Self::start_in(Path::new("."))
+    }
+
+    fn start_in(dir: &Path) -> Self {
+        let mut child = Command::new(assert_cmd::cargo::cargo_bin!("sv-mint"))
+            .arg("lsp")
+            .current_dir(dir)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .expect("failed to spawn sv-mint lsp");
+        let stdin = child.stdin.take().unwrap();
+        let stdout = BufReader::new(child.stdout.take().unwrap());
+        Self {
+            child,
+            stdin,
+            stdout,
+            next_id: 0,
+        }
+    }
+
+    fn notify(&mut self, method: &str, params: Value) {
+        let msg = json!({"jsonrpc": "2.0", "method": method, "params": params});
+        write_message(&mut self.stdin, &msg).unwrap();
+    }
+
+    fn request(&mut self, method: &str, params: Value) -> Value {
+        self.next_id += 1;
+        let id = self.next_id;
+        let msg = json!({"jsonrpc": "2.0", "id": id, "method": method, "params": params});
+        write_message(&mut self.stdin, &msg).unwrap();
+        loop {
+            let msg = self.recv();
+            if msg["id"] == json!(id) {
+                return msg["result"].clone();
+            }
+        }
+    }
+
+    fn diagnostics(&mut self, uri: &str) -> Vec<Value> {
+        loop {
+            let msg = self.recv();
+            if msg["method"] == "textDocument/publishDiagnostics" && msg["params"]["uri"] == uri {
+                return msg["params"]["diagnostics"].as_array().unwrap().clone();
+            }
+        }
+    }
+
+    fn recv(&mut self) -> Value {
+        read_message(&mut self.stdout).unwrap().expect("server closed stdout")
+    }
+
+    fn exit(mut self) {
+        assert_eq!(self.request("shutdown", Value::Null), Value::Null);
+        self.notify("exit", Value::Null);
+        self.stdin.flush().unwrap();
+        let status = self.child.wait().unwrap();
+        assert!(status.success(), "{:?}", status);
+    }
+}
+
+#[test]
+fn lsp_publishes_diagnostics_and_quick_fixes_for_unsaved_buffers() {
+    let tmp = tempfile::tempdir().unwrap();
+    let uri = format!("file://{}/top.sv", tmp.path().display());
+    let bad = std::fs::read_to_string("fixtures/rules/sensitivity_list_uses_commas/bad.sv")
+        .unwrap()
+        .replace("sensitivity_or_violation", "BadName");
+
+    let mut client = Client::start();
+    let init = client.request("initialize", json!({"processId": null, "capabilities": {}}));
+    assert_eq!(init["capabilities"]["textDocumentSync"]["change"], 1);
+    assert_eq!(init["serverInfo"]["name"], "sv-mint");
+    client.notify("initialized", json!({}));
+
+    client.notify(
+        "textDocument/didOpen",
+        json!({"textDocument": {"uri": uri, "languageId": "systemverilog", "version": 1, "text": bad}}),
+    );
+    let diags = client.diagnostics(&uri);
+    let naming = diags
+        .iter()
+        .find(|d| d["code"] == "module_names_lower_snake")
+        .unwrap_or_else(|| panic!("{:?}", diags));
+    assert_eq!(naming["range"]["start"], json!({"line": 2, "character": 7}));
+    assert_eq!(naming["source"], "sv-mint");
+    assert_eq!(naming["severity"], 2);
+    assert!(diags.iter().any(|d| d["code"] == "sensitivity_list_uses_commas"));
+
+    let actions = client.request(
+        "textDocument/codeAction",
+        json!({
+            "textDocument": {"uri": uri},
+            "range": {"start": {"line": 8, "character": 0}, "end": {"line": 8, "character": 0}},
+            "context": {"diagnostics": []},
+        }),
+    );
+    let actions = actions.as_array().unwrap();
+    let fix = actions
+        .iter()
+        .find(|a| a["title"] == "replace 'or' with ','")
+        .unwrap_or_else(|| panic!("{:?}", actions));
+    assert_eq!(fix["kind"], "quickfix");
+    let edits = fix["edit"]["changes"][uri.as_str()].as_array().unwrap();
+    assert_eq!(edits[0]["newText"], ",");
+    assert_eq!(edits[0]["range"]["start"]["line"], 8);
+
+    client.notify(
+        "textDocument/didChange",
+        json!({
+            "textDocument": {"uri": uri, "version": 2},
+            "contentChanges": [{"text": "`default_nettype none\n\nmodule top;\nendmodule\n\n`default_nettype wire\n"}],
+        }),
+    );
+    let diags = client.diagnostics(&uri);
+    assert!(diags.is_empty(), "{:?}", diags);
+
+    client.exit();
+}
+
+#[test]
+fn lsp_resolves_the_config_for_each_document() {
+    let tmp = tempfile::tempdir().unwrap();
+    let source = "module BadName(input logic BadPort);\nendmodule\n";
+    let mut uris = Vec::new();
+    for (dir, rule) in [("a", "module_names_lower_snake"), ("b", "port_names_lower_snake")] {
+        let dir = tmp.path().join(dir);
+        std::fs::create_dir(&dir).unwrap();
+        std::fs::write(
+            dir.join("sv-mint.toml"),
+            format!("[[rule]]\nid = \"{rule}\"\nnative = true\n"),
+        )
+        .unwrap();
+        uris.push((format!("file://{}/top.sv", dir.display()), rule));
+    }
+
+    let mut client = Client::start_in(tmp.path());
+    client.request("initialize", json!({"processId": null, "capabilities": {}}));
+    client.notify("initialized", json!({}));
+    for (uri, rule) in &uris {
+        client.notify(
+            "textDocument/didOpen",
+            json!({"textDocument": {"uri": uri, "languageId": "systemverilog", "version": 1, "text": source}}),
+        );
+        let diags = client.diagnostics(uri);
+        let codes: Vec<&Value> = diags.iter().map(|d| &d["code"]).collect();
+        assert_eq!(codes, [rule], "{:?}", diags);
+    }
+
+    client.exit();
+}